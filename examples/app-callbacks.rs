@@ -0,0 +1,67 @@
+use sdl3::app::{App, AppResult};
+use sdl3::event::Event;
+use sdl3::pixels::Color;
+use sdl3::render::Canvas;
+use sdl3::video::Window;
+use sdl3::Sdl;
+
+use std::error::Error;
+use std::f64::consts::PI;
+use std::time::Instant;
+
+const WINDOW_WIDTH: u32 = 640;
+const WINDOW_HEIGHT: u32 = 480;
+
+struct ChangeTheColor {
+    canvas: Canvas<Window>,
+    start_time: Instant,
+}
+
+impl App for ChangeTheColor {
+    fn init(sdl: &Sdl, _args: &[String]) -> Result<Self, Box<dyn Error>> {
+        let video_subsystem = sdl.video()?;
+        let window = video_subsystem
+            .window(
+                "Change the color of the screen from SDL's main callbacks",
+                WINDOW_WIDTH,
+                WINDOW_HEIGHT,
+            )
+            .position_centered()
+            .build()?;
+
+        Ok(ChangeTheColor {
+            canvas: window.into_canvas(),
+            start_time: Instant::now(),
+        })
+    }
+
+    fn iterate(&mut self) -> AppResult {
+        let elapsed_time = self.start_time.elapsed().as_secs_f64();
+
+        // Calculate color values using sine wave
+        let red = 0.5 + 0.5 * (elapsed_time * 2.0 * PI).sin();
+        let green = 0.5 + 0.5 * ((elapsed_time * 2.0 * PI) + (2.0 * PI / 3.0)).sin();
+        let blue = 0.5 + 0.5 * ((elapsed_time * 2.0 * PI) + (4.0 * PI / 3.0)).sin();
+
+        self.canvas.set_draw_color(Color::RGB(
+            (red * 255.0) as u8,
+            (green * 255.0) as u8,
+            (blue * 255.0) as u8,
+        ));
+        self.canvas.clear();
+        self.canvas.present();
+
+        AppResult::Continue
+    }
+
+    fn event(&mut self, event: Event) -> AppResult {
+        match event {
+            Event::Quit { .. } => AppResult::Success,
+            _ => AppResult::Continue,
+        }
+    }
+}
+
+fn main() {
+    std::process::exit(sdl3::app::run::<ChangeTheColor>());
+}
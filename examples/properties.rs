@@ -115,12 +115,14 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Set the autopointer again
     properties.set("autopointer", Box::new(test.clone())).ok();
-    // semi-safely borrow a pointer property by holding a lock on properties
-    properties
-        .with("autopointer", |value: &TestData| {
-            println!("Borrowed value: {value:?}");
-        })
-        .ok();
+    // borrow a pointer property by holding a lock on properties, the type must match the box
+    unsafe {
+        properties
+            .with("autopointer", |value: &TestData| {
+                println!("Borrowed value: {value:?}");
+            })
+            .ok();
+    }
     // Overwrite the property, this will drop the previous value
     properties.set("autopointer", Box::new(test.clone())).ok();
 
@@ -143,6 +145,21 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
         }))
         .ok();
 
+    // Or iterate over the names and types, and read the values of any type
+    for (name, property_type) in properties.iter()? {
+        if property_type != PropertyType::POINTER {
+            println!("Iteration: {name} = {:?}", properties.get_value(&name)?);
+        }
+    }
+
+    // Hold a lock to read several properties consistently
+    {
+        let locked = properties.lock()?;
+        let number: i64 = locked.get(nprop, 0)?;
+        let string: String = locked.get(sprop, String::new())?;
+        println!("Locked: {number} {string}");
+    }
+
     // Global properties are not destroyed
     let global = Properties::global().unwrap();
     global.set("persistent", "rawr x3").ok();
@@ -0,0 +1,215 @@
+//! Main-callback application entry point
+//!
+//! Instead of owning the main loop, an application can implement [`App`] and hand control to
+//! SDL with [`run`]. SDL then calls back into the app to initialize, to run one iteration of
+//! the main loop, to deliver events, and to shut down. This is required on some platforms and
+//! by some embedders, and works everywhere else too.
+//!
+//! # Example
+//! ```no_run
+//! use sdl3::app::{App, AppResult};
+//! use sdl3::event::Event;
+//! use sdl3::pixels::Color;
+//! use sdl3::render::Canvas;
+//! use sdl3::video::Window;
+//! use sdl3::Sdl;
+//!
+//! struct Game {
+//!     canvas: Canvas<Window>,
+//!     frame: u8,
+//! }
+//!
+//! impl App for Game {
+//!     fn init(sdl: &Sdl, _args: &[String]) -> Result<Self, Box<dyn std::error::Error>> {
+//!         let video_subsystem = sdl.video()?;
+//!         let window = video_subsystem.window("rust-sdl3 demo", 800, 600).build()?;
+//!         Ok(Game {
+//!             canvas: window.into_canvas(),
+//!             frame: 0,
+//!         })
+//!     }
+//!
+//!     fn iterate(&mut self) -> AppResult {
+//!         self.frame = self.frame.wrapping_add(1);
+//!         self.canvas.set_draw_color(Color::RGB(self.frame, 64, 255 - self.frame));
+//!         self.canvas.clear();
+//!         self.canvas.present();
+//!         AppResult::Continue
+//!     }
+//!
+//!     fn event(&mut self, event: Event) -> AppResult {
+//!         match event {
+//!             Event::Quit { .. } => AppResult::Success,
+//!             _ => AppResult::Continue,
+//!         }
+//!     }
+//! }
+//!
+//! fn main() {
+//!     std::process::exit(sdl3::app::run::<Game>());
+//! }
+//! ```
+
+use crate::event::Event;
+use crate::log;
+use crate::sys;
+use crate::Sdl;
+use libc::{c_char, c_int, c_void};
+use std::error;
+use std::ffi::{CStr, CString};
+use std::ptr;
+use sys::init::SDL_AppResult;
+
+/// The value returned from the [`App`] callbacks to tell SDL how to proceed.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum AppResult {
+    /// Keep running the main loop.
+    Continue,
+    /// Terminate the app and report success to the platform.
+    Success,
+    /// Terminate the app and report failure to the platform.
+    Failure,
+}
+
+impl AppResult {
+    pub fn from_ll(raw: SDL_AppResult) -> AppResult {
+        match raw {
+            SDL_AppResult::CONTINUE => AppResult::Continue,
+            SDL_AppResult::SUCCESS => AppResult::Success,
+            _ => AppResult::Failure,
+        }
+    }
+
+    pub fn to_ll(self) -> SDL_AppResult {
+        match self {
+            AppResult::Continue => SDL_AppResult::CONTINUE,
+            AppResult::Success => SDL_AppResult::SUCCESS,
+            AppResult::Failure => SDL_AppResult::FAILURE,
+        }
+    }
+}
+
+/// An application driven by SDL's main callbacks.
+///
+/// All methods are called on the main thread. Nothing here depends on being driven by
+/// [`run`], so the same type can also be driven from a hand-written loop over
+/// [`EventPump::poll_iter`](crate::EventPump::poll_iter).
+pub trait App: Sized {
+    /// Called once at startup.
+    ///
+    /// `sdl` is initialized already; subsystems should be created from it and stored in the
+    /// app as needed. `args` are the command line arguments, including the program name.
+    ///
+    /// Returning an error logs it and terminates the app with [`AppResult::Failure`].
+    fn init(sdl: &Sdl, args: &[String]) -> Result<Self, Box<dyn error::Error>>;
+
+    /// Called once per frame to run one iteration of the main loop.
+    fn iterate(&mut self) -> AppResult;
+
+    /// Called for each new event.
+    ///
+    /// By default [`Event::Quit`] terminates the app successfully and all other events are
+    /// ignored.
+    fn event(&mut self, event: Event) -> AppResult {
+        match event {
+            Event::Quit { .. } => AppResult::Success,
+            _ => AppResult::Continue,
+        }
+    }
+
+    /// Called once before terminating, with the result that caused the app to stop.
+    ///
+    /// The app is dropped afterwards, before SDL itself is shut down.
+    fn quit(&mut self, result: AppResult) {
+        let _ = result;
+    }
+}
+
+struct AppState<A> {
+    // Declared first so the app is dropped before the `Sdl` context.
+    app: A,
+    _sdl: Sdl,
+}
+
+unsafe extern "C" fn app_init<A: App>(
+    appstate: *mut *mut c_void,
+    argc: c_int,
+    argv: *mut *mut c_char,
+) -> SDL_AppResult {
+    let args: Vec<String> = (0..argc as isize)
+        .map(|i| {
+            CStr::from_ptr(*argv.offset(i))
+                .to_string_lossy()
+                .into_owned()
+        })
+        .collect();
+
+    let sdl = match crate::init() {
+        Ok(sdl) => sdl,
+        Err(e) => {
+            log::log_critical(log::Category::Application, &e.to_string());
+            return SDL_AppResult::FAILURE;
+        }
+    };
+
+    match A::init(&sdl, &args) {
+        Ok(app) => {
+            *appstate = Box::into_raw(Box::new(AppState { app, _sdl: sdl })) as *mut c_void;
+            SDL_AppResult::CONTINUE
+        }
+        Err(e) => {
+            log::log_critical(log::Category::Application, &e.to_string());
+            SDL_AppResult::FAILURE
+        }
+    }
+}
+
+unsafe extern "C" fn app_iterate<A: App>(appstate: *mut c_void) -> SDL_AppResult {
+    let state = &mut *(appstate as *mut AppState<A>);
+    state.app.iterate().to_ll()
+}
+
+unsafe extern "C" fn app_event<A: App>(
+    appstate: *mut c_void,
+    event: *mut sys::events::SDL_Event,
+) -> SDL_AppResult {
+    let state = &mut *(appstate as *mut AppState<A>);
+    state.app.event(Event::from_ll(*event)).to_ll()
+}
+
+unsafe extern "C" fn app_quit<A: App>(appstate: *mut c_void, result: SDL_AppResult) {
+    // `appstate` is null if `App::init` failed.
+    if !appstate.is_null() {
+        let mut state = Box::from_raw(appstate as *mut AppState<A>);
+        state.app.quit(AppResult::from_ll(result));
+    }
+}
+
+/// Runs the app `A` through SDL's main callbacks.
+///
+/// This initializes SDL, calls [`App::init`], and then keeps calling [`App::iterate`] and
+/// [`App::event`] until one of them returns something other than [`AppResult::Continue`].
+/// The returned value is the process exit code and should be passed to
+/// [`std::process::exit`].
+///
+/// Must be called from the main thread. On platforms where SDL cannot block here, this may
+/// return immediately while the app keeps running.
+#[doc(alias = "SDL_EnterAppMainCallbacks")]
+pub fn run<A: App>() -> i32 {
+    let args: Vec<CString> = std::env::args()
+        .map(|arg| CString::new(arg).unwrap_or_default())
+        .collect();
+    let mut argv: Vec<*mut c_char> = args.iter().map(|arg| arg.as_ptr() as *mut _).collect();
+    argv.push(ptr::null_mut());
+
+    unsafe {
+        sys::main::SDL_EnterAppMainCallbacks(
+            args.len() as c_int,
+            argv.as_mut_ptr(),
+            Some(app_init::<A>),
+            Some(app_iterate::<A>),
+            Some(app_event::<A>),
+            Some(app_quit::<A>),
+        )
+    }
+}
@@ -0,0 +1,194 @@
+//! Handling of SDL's internal assertions
+//!
+//! By default a failed assertion inside SDL shows a native dialog or aborts the process. With
+//! [`set_handler`] a closure decides what happens instead, e.g. [`log_handler`] logs the failure
+//! and carries on, so that a test run can check the [`report`] at the end and fail cleanly.
+//!
+//! # Example
+//! ```no_run
+//! use sdl3::assert::{self, AssertState};
+//!
+//! assert::set_handler(|assertion| {
+//!     eprintln!("{}", assertion);
+//!     AssertState::Ignore
+//! });
+//!
+//! // ... run the app ...
+//!
+//! let report = assert::report();
+//! for assertion in &report {
+//!     eprintln!("{}", assertion);
+//! }
+//! assert!(report.is_empty());
+//! ```
+
+use crate::log::{self, Category, Priority};
+use crate::sys;
+use crate::util::ClosureSlot;
+use std::ffi::{c_void, CStr};
+use std::fmt;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr::null_mut;
+use std::sync::Arc;
+use sys::assert::*;
+
+/// What to do about a failed assertion.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AssertState {
+    /// Check the assertion again, e.g. after fixing things up in a debugger.
+    Retry,
+    /// Trigger a breakpoint.
+    Break,
+    /// Terminate the program.
+    Abort,
+    /// Ignore this failure.
+    Ignore,
+    /// Ignore this and all further failures of the same assertion.
+    AlwaysIgnore,
+}
+
+impl AssertState {
+    pub fn from_ll(state: SDL_AssertState) -> AssertState {
+        match state {
+            SDL_ASSERTION_RETRY => AssertState::Retry,
+            SDL_ASSERTION_BREAK => AssertState::Break,
+            SDL_ASSERTION_IGNORE => AssertState::Ignore,
+            SDL_ASSERTION_ALWAYS_IGNORE => AssertState::AlwaysIgnore,
+            _ => AssertState::Abort,
+        }
+    }
+
+    pub fn to_ll(self) -> SDL_AssertState {
+        match self {
+            AssertState::Retry => SDL_ASSERTION_RETRY,
+            AssertState::Break => SDL_ASSERTION_BREAK,
+            AssertState::Abort => SDL_ASSERTION_ABORT,
+            AssertState::Ignore => SDL_ASSERTION_IGNORE,
+            AssertState::AlwaysIgnore => SDL_ASSERTION_ALWAYS_IGNORE,
+        }
+    }
+}
+
+/// A failed assertion, as passed to the handler and listed in the [`report`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assertion {
+    /// The asserted condition as written in the source.
+    pub condition: String,
+    pub function: String,
+    pub filename: String,
+    pub line: i32,
+    /// How often the assertion failed so far.
+    pub trigger_count: u32,
+    /// `true` if further failures are ignored.
+    pub always_ignore: bool,
+}
+
+impl Assertion {
+    /// # Safety
+    /// The strings of `data` must be null or valid C strings.
+    pub unsafe fn from_ll(data: &SDL_AssertData) -> Assertion {
+        let string = |ptr: *const libc::c_char| {
+            if ptr.is_null() {
+                String::new()
+            } else {
+                CStr::from_ptr(ptr).to_string_lossy().into_owned()
+            }
+        };
+        Assertion {
+            condition: string(data.condition),
+            function: string(data.function),
+            filename: string(data.filename),
+            line: data.linenum,
+            trigger_count: data.trigger_count,
+            always_ignore: data.always_ignore,
+        }
+    }
+}
+
+impl fmt::Display for Assertion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Assertion failure at {} ({}:{}), triggered {} {}: '{}'",
+            self.function,
+            self.filename,
+            self.line,
+            self.trigger_count,
+            if self.trigger_count == 1 {
+                "time"
+            } else {
+                "times"
+            },
+            self.condition
+        )
+    }
+}
+
+type HandlerClosure = dyn Fn(&Assertion) -> AssertState + Send + Sync;
+
+static HANDLER: ClosureSlot<HandlerClosure> = ClosureSlot::new();
+
+unsafe extern "C" fn closure_assertion_handler(
+    data: *const SDL_AssertData,
+    _userdata: *mut c_void,
+) -> SDL_AssertState {
+    let Some(closure) = HANDLER.get() else {
+        return SDL_ASSERTION_ABORT;
+    };
+    let assertion = Assertion::from_ll(&*data);
+    // A panic can't unwind into SDL, so it fails like an unhandled assertion instead.
+    catch_unwind(AssertUnwindSafe(|| closure(&assertion)))
+        .unwrap_or(AssertState::Abort)
+        .to_ll()
+}
+
+/// Calls `handler` when an assertion inside SDL fails, and does what it returns.
+///
+/// The handler may be called from any thread. If it panics, the program is aborted.
+#[doc(alias = "SDL_SetAssertionHandler")]
+pub fn set_handler<F>(handler: F)
+where
+    F: Fn(&Assertion) -> AssertState + Send + Sync + 'static,
+{
+    HANDLER.replace(Some(Arc::new(handler)));
+    unsafe { SDL_SetAssertionHandler(Some(closure_assertion_handler), null_mut()) };
+}
+
+/// Restores SDL's default handler, which asks the user or aborts, depending on the platform.
+#[doc(alias = "SDL_SetAssertionHandler")]
+pub fn reset_handler() {
+    unsafe { SDL_SetAssertionHandler(None, null_mut()) };
+    HANDLER.replace(None);
+}
+
+/// A handler that logs the failure as a critical message in [`Category::Assert`] and
+/// ignores it.
+///
+/// ```no_run
+/// sdl3::assert::set_handler(sdl3::assert::log_handler);
+/// ```
+pub fn log_handler(assertion: &Assertion) -> AssertState {
+    log::log_message(Category::Assert, Priority::Critical, &assertion.to_string());
+    AssertState::Ignore
+}
+
+/// Lists all assertions that failed since the start or the last [`reset_report`].
+#[doc(alias = "SDL_GetAssertionReport")]
+pub fn report() -> Vec<Assertion> {
+    let mut assertions = Vec::new();
+    unsafe {
+        let mut item = SDL_GetAssertionReport();
+        while !item.is_null() {
+            assertions.push(Assertion::from_ll(&*item));
+            item = (*item).next;
+        }
+    }
+    assertions
+}
+
+/// Clears the [`report`] and the trigger counts, and stops ignoring assertions that were set
+/// to [`AssertState::AlwaysIgnore`].
+#[doc(alias = "SDL_ResetAssertionReport")]
+pub fn reset_report() {
+    unsafe { SDL_ResetAssertionReport() };
+}
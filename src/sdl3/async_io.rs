@@ -0,0 +1,599 @@
+//! Asynchronous File I/O
+//!
+//! Reads and writes files in the background without stalling the calling thread. Every task is
+//! submitted together with an [`AsyncIOQueue`], and its [`AsyncIOOutcome`] shows up on that
+//! queue once the task finishes. Buffers are moved into the task on submission and handed back
+//! in the outcome.
+//!
+//! The queue can be polled once per frame with [`AsyncIOQueue::get_result`], waited on with
+//! [`AsyncIOQueue::wait_result`], or awaited with [`AsyncIOQueue::next_outcome`].
+//!
+//! # Example
+//! ```no_run
+//! use sdl3::async_io::{load_file_async, AsyncIOQueue, AsyncIOResult};
+//!
+//! let queue = AsyncIOQueue::new().unwrap();
+//! let task = load_file_async("assets/level1.map", &queue).unwrap();
+//!
+//! loop {
+//!     // ... render a frame ...
+//!     if let Some(outcome) = queue.get_result() {
+//!         assert_eq!(outcome.task, task);
+//!         assert_eq!(outcome.result, AsyncIOResult::Complete);
+//!         println!("loaded {} bytes", outcome.buffer.len());
+//!         break;
+//!     }
+//! }
+//! ```
+
+use crate::get_error;
+use crate::sys;
+use crate::Error;
+use libc::c_void;
+use std::collections::VecDeque;
+use std::ffi::CString;
+use std::future::Future;
+use std::mem;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::task::{Context, Poll, Waker};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use sys::asyncio::{
+    SDL_AsyncIO, SDL_AsyncIOOutcome, SDL_AsyncIOQueue, SDL_AsyncIOResult, SDL_AsyncIOTaskType,
+};
+
+/// Identifies a task submitted to an [`AsyncIOQueue`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct TaskId(u64);
+
+impl TaskId {
+    fn next() -> TaskId {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        TaskId(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum AsyncIOTaskType {
+    Read,
+    Write,
+    Close,
+}
+
+impl AsyncIOTaskType {
+    pub fn from_ll(raw: SDL_AsyncIOTaskType) -> AsyncIOTaskType {
+        match raw {
+            SDL_AsyncIOTaskType::READ => AsyncIOTaskType::Read,
+            SDL_AsyncIOTaskType::WRITE => AsyncIOTaskType::Write,
+            _ => AsyncIOTaskType::Close,
+        }
+    }
+
+    pub fn to_ll(self) -> SDL_AsyncIOTaskType {
+        match self {
+            AsyncIOTaskType::Read => SDL_AsyncIOTaskType::READ,
+            AsyncIOTaskType::Write => SDL_AsyncIOTaskType::WRITE,
+            AsyncIOTaskType::Close => SDL_AsyncIOTaskType::CLOSE,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum AsyncIOResult {
+    /// The task finished successfully.
+    Complete,
+    /// The task failed, [`get_error`](crate::get_error) has the details.
+    Failure,
+    /// The task was canceled before it finished.
+    Canceled,
+}
+
+impl AsyncIOResult {
+    pub fn from_ll(raw: SDL_AsyncIOResult) -> AsyncIOResult {
+        match raw {
+            SDL_AsyncIOResult::COMPLETE => AsyncIOResult::Complete,
+            SDL_AsyncIOResult::CANCELED => AsyncIOResult::Canceled,
+            _ => AsyncIOResult::Failure,
+        }
+    }
+
+    pub fn to_ll(self) -> SDL_AsyncIOResult {
+        match self {
+            AsyncIOResult::Complete => SDL_AsyncIOResult::COMPLETE,
+            AsyncIOResult::Failure => SDL_AsyncIOResult::FAILURE,
+            AsyncIOResult::Canceled => SDL_AsyncIOResult::CANCELED,
+        }
+    }
+}
+
+/// The result of a finished task.
+#[derive(Debug)]
+pub struct AsyncIOOutcome {
+    /// The task this is the outcome of.
+    pub task: TaskId,
+    pub task_type: AsyncIOTaskType,
+    pub result: AsyncIOResult,
+    /// The buffer given to the task. For reads it is truncated to the bytes actually read,
+    /// for [`load_file_async`] it holds the file contents, and for closes it is empty.
+    pub buffer: Vec<u8>,
+    /// The file offset the task started at.
+    pub offset: u64,
+    pub bytes_requested: u64,
+    pub bytes_transferred: u64,
+}
+
+/// Passed to SDL as the userdata of every task.
+struct TaskData {
+    id: TaskId,
+    /// `None` if SDL allocated the buffer.
+    buffer: Option<Vec<u8>>,
+}
+
+/// The state shared with the helper thread, which is the only one blocking in SDL.
+#[derive(Default)]
+struct WaiterState {
+    /// Outcomes taken from SDL by the helper thread, handed out before any others.
+    outcomes: VecDeque<AsyncIOOutcome>,
+    /// The wakers of pending futures, woken whenever the helper thread wakes up.
+    wakers: Vec<Waker>,
+    thread: Option<JoinHandle<()>>,
+    /// Set once the helper thread returned.
+    finished: bool,
+    shutdown: bool,
+    /// Incremented by [`AsyncIOQueue::signal`].
+    signals: u64,
+}
+
+struct QueueInner {
+    raw: *mut SDL_AsyncIOQueue,
+    /// Tasks whose outcome wasn't taken from SDL yet.
+    pending: AtomicUsize,
+    waiter: Mutex<WaiterState>,
+    /// Notified whenever `waiter` changes.
+    changed: Condvar,
+}
+
+unsafe impl Send for QueueInner {}
+unsafe impl Sync for QueueInner {}
+
+impl QueueInner {
+    /// Returns the id and the userdata for a new task.
+    ///
+    /// The userdata may be freed by another thread as soon as the task is started, so the id
+    /// has to be taken before.
+    fn submit(&self, buffer: Option<Vec<u8>>) -> (TaskId, *mut TaskData) {
+        self.pending.fetch_add(1, Ordering::SeqCst);
+        let id = TaskId::next();
+        (id, Box::into_raw(Box::new(TaskData { id, buffer })))
+    }
+
+    /// Takes back the userdata of a task that SDL refused to start.
+    unsafe fn cancel(&self, task: *mut TaskData) {
+        self.pending.fetch_sub(1, Ordering::SeqCst);
+        drop(Box::from_raw(task));
+    }
+
+    unsafe fn outcome_from_ll(&self, raw: SDL_AsyncIOOutcome) -> AsyncIOOutcome {
+        self.pending.fetch_sub(1, Ordering::SeqCst);
+        let task = Box::from_raw(raw.userdata as *mut TaskData);
+        let task_type = AsyncIOTaskType::from_ll(raw.r#type);
+        let buffer = match task.buffer {
+            Some(mut buffer) => {
+                if task_type == AsyncIOTaskType::Read {
+                    buffer.truncate(raw.bytes_transferred as usize);
+                }
+                buffer
+            }
+            None if raw.buffer.is_null() => Vec::new(),
+            None => {
+                let data = std::slice::from_raw_parts(
+                    raw.buffer as *const u8,
+                    raw.bytes_transferred as usize,
+                )
+                .to_vec();
+                sys::stdinc::SDL_free(raw.buffer);
+                data
+            }
+        };
+        AsyncIOOutcome {
+            task: task.id,
+            task_type,
+            result: AsyncIOResult::from_ll(raw.result),
+            buffer,
+            offset: raw.offset,
+            bytes_requested: raw.bytes_requested,
+            bytes_transferred: raw.bytes_transferred,
+        }
+    }
+
+    fn waiter(&self) -> MutexGuard<'_, WaiterState> {
+        self.waiter.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn get_raw_result(&self) -> Option<AsyncIOOutcome> {
+        let mut raw = SDL_AsyncIOOutcome::default();
+        if unsafe { sys::asyncio::SDL_GetAsyncIOResult(self.raw, &mut raw) } {
+            Some(unsafe { self.outcome_from_ll(raw) })
+        } else {
+            None
+        }
+    }
+
+    fn wait_raw_result(&self, timeout: i32) -> Option<AsyncIOOutcome> {
+        let mut raw = SDL_AsyncIOOutcome::default();
+        if unsafe { sys::asyncio::SDL_WaitAsyncIOResult(self.raw, &mut raw, timeout) } {
+            Some(unsafe { self.outcome_from_ll(raw) })
+        } else {
+            None
+        }
+    }
+
+    fn get_result(&self) -> Option<AsyncIOOutcome> {
+        let buffered = self.waiter().outcomes.pop_front();
+        buffered.or_else(|| self.get_raw_result())
+    }
+
+    /// Waits for the helper thread to buffer an outcome, `timeout` is `None` for no limit.
+    fn wait_result(self: &Arc<Self>, timeout: Option<Duration>) -> Option<AsyncIOOutcome> {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        let mut state = self.waiter();
+        let signals = state.signals;
+        loop {
+            if let Some(outcome) = state.outcomes.pop_front() {
+                return Some(outcome);
+            }
+            if let Some(outcome) = self.get_raw_result() {
+                return Some(outcome);
+            }
+            if state.signals != signals || state.shutdown {
+                return None;
+            }
+            self.start_waiter(&mut state);
+            state = match deadline {
+                None => self.changed.wait(state).unwrap_or_else(|e| e.into_inner()),
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return None;
+                    }
+                    let wait = self.changed.wait_timeout(state, deadline - now);
+                    wait.unwrap_or_else(|e| e.into_inner()).0
+                }
+            };
+        }
+    }
+
+    fn start_waiter(self: &Arc<Self>, state: &mut WaiterState) {
+        if state.thread.is_none() {
+            let inner = self.clone();
+            state.thread = Some(thread::spawn(move || inner.run_waiter()));
+        }
+    }
+
+    /// The helper thread, which waits on the queue until it is dropped and buffers the
+    /// outcomes for [`AsyncIOQueue::wait_result`] and the futures.
+    fn run_waiter(&self) {
+        loop {
+            if self.waiter().shutdown {
+                break;
+            }
+            let outcome = self.wait_raw_result(-1);
+
+            let mut state = self.waiter();
+            if let Some(outcome) = outcome {
+                state.outcomes.push_back(outcome);
+            }
+            for waker in state.wakers.drain(..) {
+                waker.wake();
+            }
+            self.changed.notify_all();
+        }
+        self.waiter().finished = true;
+        self.changed.notify_all();
+    }
+}
+
+impl Drop for QueueInner {
+    #[doc(alias = "SDL_DestroyAsyncIOQueue")]
+    fn drop(&mut self) {
+        // Collect the outstanding outcomes ourselves so their buffers are freed.
+        while self.pending.load(Ordering::SeqCst) > 0 {
+            self.wait_raw_result(-1);
+        }
+        unsafe { sys::asyncio::SDL_DestroyAsyncIOQueue(self.raw) }
+    }
+}
+
+/// A queue that collects the outcomes of asynchronous I/O tasks.
+///
+/// Dropping the queue blocks until all tasks submitted to it are finished.
+pub struct AsyncIOQueue {
+    inner: Arc<QueueInner>,
+}
+
+impl AsyncIOQueue {
+    #[doc(alias = "SDL_CreateAsyncIOQueue")]
+    pub fn new() -> Result<AsyncIOQueue, Error> {
+        let raw = unsafe { sys::asyncio::SDL_CreateAsyncIOQueue() };
+        if raw.is_null() {
+            Err(get_error())
+        } else {
+            Ok(AsyncIOQueue {
+                inner: Arc::new(QueueInner {
+                    raw,
+                    pending: AtomicUsize::new(0),
+                    waiter: Mutex::new(WaiterState::default()),
+                    changed: Condvar::new(),
+                }),
+            })
+        }
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut SDL_AsyncIOQueue {
+        self.inner.raw
+    }
+
+    /// Returns the number of submitted tasks whose outcome was not taken from the queue yet.
+    pub fn pending(&self) -> usize {
+        self.inner.pending.load(Ordering::SeqCst) + self.inner.waiter().outcomes.len()
+    }
+
+    /// Returns the outcome of a finished task, without blocking.
+    #[doc(alias = "SDL_GetAsyncIOResult")]
+    pub fn get_result(&self) -> Option<AsyncIOOutcome> {
+        self.inner.get_result()
+    }
+
+    /// Blocks until a task finishes and returns its outcome.
+    ///
+    /// Returns `None` if the queue was [signaled](Self::signal) while waiting.
+    ///
+    /// The first time a thread has to wait, a helper thread is started that waits on the queue
+    /// for as long as it exists, see [`next_outcome`](Self::next_outcome).
+    #[doc(alias = "SDL_WaitAsyncIOResult")]
+    pub fn wait_result(&self) -> Option<AsyncIOOutcome> {
+        self.inner.wait_result(None)
+    }
+
+    /// Like [`wait_result`](Self::wait_result), but gives up after `timeout` milliseconds.
+    #[doc(alias = "SDL_WaitAsyncIOResult")]
+    pub fn wait_result_timeout(&self, timeout: u32) -> Option<AsyncIOOutcome> {
+        self.inner
+            .wait_result(Some(Duration::from_millis(timeout as u64)))
+    }
+
+    /// Wakes up all threads blocked in [`wait_result`](Self::wait_result), making them
+    /// return `None`.
+    #[doc(alias = "SDL_SignalAsyncIOQueue")]
+    pub fn signal(&self) {
+        let mut state = self.inner.waiter();
+        state.signals += 1;
+        self.inner.changed.notify_all();
+    }
+
+    /// Returns a future that resolves to the outcome of the next task to finish.
+    ///
+    /// The first time a future or [`wait_result`](Self::wait_result) has to wait, a helper
+    /// thread is started that waits on the queue for as long as it exists. Outcomes it receives
+    /// are kept for the next future, [`get_result`](Self::get_result) or
+    /// [`wait_result`](Self::wait_result), so dropping a future before it resolves doesn't
+    /// lose any.
+    pub fn next_outcome(&self) -> NextOutcome<'_> {
+        NextOutcome { queue: self }
+    }
+}
+
+impl Drop for AsyncIOQueue {
+    fn drop(&mut self) {
+        let mut state = self.inner.waiter();
+        state.shutdown = true;
+        self.inner.changed.notify_all();
+        let Some(thread) = state.thread.take() else {
+            return;
+        };
+        // SDL's signal only wakes a thread that is already waiting, so it's repeated until the
+        // helper thread saw the shutdown flag.
+        while !state.finished {
+            unsafe { sys::asyncio::SDL_SignalAsyncIOQueue(self.inner.raw) };
+            let wait = self
+                .inner
+                .changed
+                .wait_timeout(state, Duration::from_millis(10));
+            state = wait.unwrap_or_else(|e| e.into_inner()).0;
+        }
+        drop(state);
+        let _ = thread.join();
+    }
+}
+
+/// The future returned by [`AsyncIOQueue::next_outcome`].
+pub struct NextOutcome<'a> {
+    queue: &'a AsyncIOQueue,
+}
+
+impl Future for NextOutcome<'_> {
+    type Output = AsyncIOOutcome;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<AsyncIOOutcome> {
+        let inner = &self.queue.inner;
+        // Locked throughout, so that the helper thread can't take an outcome and wake the
+        // futures in between.
+        let mut state = inner.waiter();
+        if let Some(outcome) = state.outcomes.pop_front() {
+            return Poll::Ready(outcome);
+        }
+        if let Some(outcome) = inner.get_raw_result() {
+            return Poll::Ready(outcome);
+        }
+
+        if !state.wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+            state.wakers.push(cx.waker().clone());
+        }
+        inner.start_waiter(&mut state);
+        Poll::Pending
+    }
+}
+
+/// A file opened for asynchronous I/O.
+///
+/// Dropping an `AsyncIO` closes it without flushing and blocks until that is done; use
+/// [`close`](Self::close) to close it asynchronously instead.
+pub struct AsyncIO {
+    raw: *mut SDL_AsyncIO,
+}
+
+unsafe impl Send for AsyncIO {}
+unsafe impl Sync for AsyncIO {}
+
+impl AsyncIO {
+    /// Opens a file, `mode` is the same as for [`IOStream::from_file`](crate::iostream::IOStream::from_file)
+    /// but text mode is not supported.
+    #[doc(alias = "SDL_AsyncIOFromFile")]
+    pub fn from_file<P: AsRef<Path>>(path: P, mode: &str) -> Result<AsyncIO, Error> {
+        let path = path_to_cstring(path.as_ref())?;
+        let mode = CString::new(mode).map_err(|e| Error(e.to_string()))?;
+        let raw = unsafe { sys::asyncio::SDL_AsyncIOFromFile(path.as_ptr(), mode.as_ptr()) };
+        if raw.is_null() {
+            Err(get_error())
+        } else {
+            Ok(AsyncIO { raw })
+        }
+    }
+
+    #[inline]
+    pub const fn raw(&self) -> *mut SDL_AsyncIO {
+        self.raw
+    }
+
+    /// Returns the size of the file in bytes.
+    #[doc(alias = "SDL_GetAsyncIOSize")]
+    pub fn size(&self) -> Result<u64, Error> {
+        let size = unsafe { sys::asyncio::SDL_GetAsyncIOSize(self.raw) };
+        if size < 0 {
+            Err(get_error())
+        } else {
+            Ok(size as u64)
+        }
+    }
+
+    /// Starts reading `buffer.len()` bytes at `offset` into `buffer`.
+    #[doc(alias = "SDL_ReadAsyncIO")]
+    pub fn read(
+        &self,
+        mut buffer: Vec<u8>,
+        offset: u64,
+        queue: &AsyncIOQueue,
+    ) -> Result<TaskId, Error> {
+        let ptr = buffer.as_mut_ptr();
+        let size = buffer.len() as u64;
+        let (id, task) = queue.inner.submit(Some(buffer));
+        unsafe {
+            if sys::asyncio::SDL_ReadAsyncIO(
+                self.raw,
+                ptr as *mut c_void,
+                offset,
+                size,
+                queue.inner.raw,
+                task as *mut c_void,
+            ) {
+                Ok(id)
+            } else {
+                queue.inner.cancel(task);
+                Err(get_error())
+            }
+        }
+    }
+
+    /// Starts writing the contents of `buffer` at `offset`.
+    #[doc(alias = "SDL_WriteAsyncIO")]
+    pub fn write(
+        &self,
+        buffer: Vec<u8>,
+        offset: u64,
+        queue: &AsyncIOQueue,
+    ) -> Result<TaskId, Error> {
+        let ptr = buffer.as_ptr();
+        let size = buffer.len() as u64;
+        let (id, task) = queue.inner.submit(Some(buffer));
+        unsafe {
+            if sys::asyncio::SDL_WriteAsyncIO(
+                self.raw,
+                ptr as *mut c_void,
+                offset,
+                size,
+                queue.inner.raw,
+                task as *mut c_void,
+            ) {
+                Ok(id)
+            } else {
+                queue.inner.cancel(task);
+                Err(get_error())
+            }
+        }
+    }
+
+    /// Starts closing the file, after all tasks already submitted for it are finished.
+    ///
+    /// With `flush` set, the close task completes only after the data reached the disk.
+    /// Errors from earlier writes may only be reported by the outcome of this task.
+    #[doc(alias = "SDL_CloseAsyncIO")]
+    pub fn close(self, flush: bool, queue: &AsyncIOQueue) -> Result<TaskId, Error> {
+        let raw = self.raw;
+        mem::forget(self);
+        let (id, task) = queue.inner.submit(None);
+        unsafe {
+            if sys::asyncio::SDL_CloseAsyncIO(raw, flush, queue.inner.raw, task as *mut c_void) {
+                Ok(id)
+            } else {
+                queue.inner.cancel(task);
+                Err(get_error())
+            }
+        }
+    }
+}
+
+impl Drop for AsyncIO {
+    #[doc(alias = "SDL_CloseAsyncIO")]
+    fn drop(&mut self) {
+        if let Ok(queue) = AsyncIOQueue::new() {
+            let (_, task) = queue.inner.submit(None);
+            unsafe {
+                if !sys::asyncio::SDL_CloseAsyncIO(
+                    self.raw,
+                    false,
+                    queue.inner.raw,
+                    task as *mut c_void,
+                ) {
+                    queue.inner.cancel(task);
+                }
+            }
+            // Dropping the queue waits for the close to finish.
+        }
+    }
+}
+
+/// Starts loading a whole file, the contents end up in the outcome's buffer.
+#[doc(alias = "SDL_LoadFileAsync")]
+pub fn load_file_async<P: AsRef<Path>>(path: P, queue: &AsyncIOQueue) -> Result<TaskId, Error> {
+    let path = path_to_cstring(path.as_ref())?;
+    let (id, task) = queue.inner.submit(None);
+    unsafe {
+        if sys::asyncio::SDL_LoadFileAsync(path.as_ptr(), queue.inner.raw, task as *mut c_void) {
+            Ok(id)
+        } else {
+            queue.inner.cancel(task);
+            Err(get_error())
+        }
+    }
+}
+
+fn path_to_cstring(path: &Path) -> Result<CString, Error> {
+    let path = path
+        .to_str()
+        .ok_or_else(|| Error(format!("Invalid path: {}", path.display())))?;
+    CString::new(path).map_err(|e| Error(e.to_string()))
+}
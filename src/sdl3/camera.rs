@@ -0,0 +1,366 @@
+//! Camera Functions
+//!
+//! Video capture from webcams and other cameras. Frames are handed out as borrowed
+//! [`SurfaceRef`]s and given back to SDL when the [`CameraFrame`] is dropped.
+//!
+//! # Example
+//! ```no_run
+//! let sdl_context = sdl3::init().unwrap();
+//! let camera_subsystem = sdl_context.camera().unwrap();
+//! let video_subsystem = sdl_context.video().unwrap();
+//!
+//! let window = video_subsystem.window("camera", 640, 480).build().unwrap();
+//! let mut canvas = window.into_canvas();
+//! let texture_creator = canvas.texture_creator();
+//!
+//! let camera_id = *camera_subsystem.cameras().unwrap().first().unwrap();
+//! let camera = camera_subsystem.open(camera_id, None).unwrap();
+//!
+//! loop {
+//!     if let Some(frame) = camera.acquire_frame() {
+//!         let texture = texture_creator.create_texture_from_surface(&*frame).unwrap();
+//!         canvas.copy(&texture, None, None).unwrap();
+//!         canvas.present();
+//!     }
+//! }
+//! ```
+
+use crate::get_error;
+use crate::pixels::{Colorspace, PixelFormat};
+use crate::surface::SurfaceRef;
+use crate::sys;
+use crate::CameraSubsystem;
+use crate::Error;
+use libc::c_void;
+use std::ffi::CStr;
+use std::fmt;
+use std::ops::Deref;
+use std::ptr;
+use sys::camera::{SDL_Camera, SDL_CameraPosition, SDL_CameraSpec};
+use sys::stdinc::SDL_free;
+
+pub type CameraId = sys::camera::SDL_CameraID;
+
+impl CameraSubsystem {
+    /// Get the name of the camera driver that is currently in use.
+    #[doc(alias = "SDL_GetCurrentCameraDriver")]
+    pub fn current_driver(&self) -> Option<&'static str> {
+        unsafe {
+            let buf = sys::camera::SDL_GetCurrentCameraDriver();
+            if buf.is_null() {
+                None
+            } else {
+                Some(CStr::from_ptr(buf as *const _).to_str().unwrap())
+            }
+        }
+    }
+
+    /// Get the instance IDs of the currently connected cameras.
+    #[doc(alias = "SDL_GetCameras")]
+    pub fn cameras(&self) -> Result<Vec<CameraId>, Error> {
+        let mut count = 0;
+        unsafe {
+            let camera_ids = sys::camera::SDL_GetCameras(&mut count);
+            if camera_ids.is_null() {
+                Err(get_error())
+            } else {
+                let ids = std::slice::from_raw_parts(camera_ids, count as usize).to_vec();
+                SDL_free(camera_ids as *mut c_void);
+                Ok(ids)
+            }
+        }
+    }
+
+    /// Get the human-readable name of a camera.
+    #[doc(alias = "SDL_GetCameraName")]
+    pub fn name_for_id(&self, camera_id: CameraId) -> Result<String, Error> {
+        unsafe {
+            let name = sys::camera::SDL_GetCameraName(camera_id);
+            if name.is_null() {
+                Err(get_error())
+            } else {
+                Ok(CStr::from_ptr(name as *const _)
+                    .to_str()
+                    .unwrap()
+                    .to_owned())
+            }
+        }
+    }
+
+    /// Get the position of a camera in relation to the system device.
+    #[doc(alias = "SDL_GetCameraPosition")]
+    pub fn position_for_id(&self, camera_id: CameraId) -> CameraPosition {
+        CameraPosition::from_ll(unsafe { sys::camera::SDL_GetCameraPosition(camera_id) })
+    }
+
+    /// Get the list of formats a camera natively supports.
+    ///
+    /// The list is sorted by preference of the device; it may be empty if the driver cannot
+    /// report formats before the device is opened.
+    #[doc(alias = "SDL_GetCameraSupportedFormats")]
+    pub fn supported_formats(&self, camera_id: CameraId) -> Result<Vec<CameraSpec>, Error> {
+        let mut count = 0;
+        unsafe {
+            let specs = sys::camera::SDL_GetCameraSupportedFormats(camera_id, &mut count);
+            if specs.is_null() {
+                Err(get_error())
+            } else {
+                let ret = std::slice::from_raw_parts(specs, count as usize)
+                    .iter()
+                    .map(|&spec| CameraSpec::from_ll(*spec))
+                    .collect();
+                SDL_free(specs as *mut c_void);
+                Ok(ret)
+            }
+        }
+    }
+
+    /// Open a camera.
+    ///
+    /// If `spec` is `None`, the camera's preferred format is used. Otherwise SDL converts
+    /// frames to the requested format if the device does not support it natively.
+    ///
+    /// On some platforms the user has to approve access to the camera first, see
+    /// [`Camera::permission_state`].
+    #[doc(alias = "SDL_OpenCamera")]
+    pub fn open(&self, camera_id: CameraId, spec: Option<&CameraSpec>) -> Result<Camera, Error> {
+        let spec = spec.map(|spec| spec.to_ll());
+        let spec_ptr = spec.as_ref().map_or(ptr::null(), |spec| spec as *const _);
+        let raw = unsafe { sys::camera::SDL_OpenCamera(camera_id, spec_ptr) };
+        if raw.is_null() {
+            Err(get_error())
+        } else {
+            Ok(Camera {
+                subsystem: self.clone(),
+                raw,
+            })
+        }
+    }
+}
+
+/// Get the names of the camera drivers compiled into SDL.
+#[doc(alias = "SDL_GetCameraDriver")]
+pub fn drivers() -> impl Iterator<Item = &'static str> {
+    let num_drivers = unsafe { sys::camera::SDL_GetNumCameraDrivers() };
+    (0..num_drivers).filter_map(|index| unsafe {
+        let buf = sys::camera::SDL_GetCameraDriver(index);
+        if buf.is_null() {
+            None
+        } else {
+            CStr::from_ptr(buf as *const _).to_str().ok()
+        }
+    })
+}
+
+/// The position of a camera in relation to the system device.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum CameraPosition {
+    Unknown,
+    FrontFacing,
+    BackFacing,
+}
+
+impl CameraPosition {
+    pub fn from_ll(raw: SDL_CameraPosition) -> CameraPosition {
+        match raw {
+            SDL_CameraPosition::FRONT_FACING => CameraPosition::FrontFacing,
+            SDL_CameraPosition::BACK_FACING => CameraPosition::BackFacing,
+            _ => CameraPosition::Unknown,
+        }
+    }
+
+    pub fn to_ll(self) -> SDL_CameraPosition {
+        match self {
+            CameraPosition::Unknown => SDL_CameraPosition::UNKNOWN,
+            CameraPosition::FrontFacing => SDL_CameraPosition::FRONT_FACING,
+            CameraPosition::BackFacing => SDL_CameraPosition::BACK_FACING,
+        }
+    }
+}
+
+/// Whether the user has approved access to a camera.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum CameraPermission {
+    /// The user has not answered yet; no frames will be delivered until they do.
+    Pending,
+    Approved,
+    Denied,
+}
+
+/// The format of the frames a camera produces.
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct CameraSpec {
+    pub format: PixelFormat,
+    pub colorspace: Colorspace,
+    pub width: i32,
+    pub height: i32,
+    /// Frame rate numerator; `framerate_numerator / framerate_denominator` is the FPS.
+    pub framerate_numerator: i32,
+    /// Frame rate denominator; `framerate_denominator / framerate_numerator` is the frame
+    /// duration in seconds.
+    pub framerate_denominator: i32,
+}
+
+impl fmt::Debug for CameraSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CameraSpec")
+            .field("format", &self.format)
+            .field("colorspace", &self.colorspace.0)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("framerate_numerator", &self.framerate_numerator)
+            .field("framerate_denominator", &self.framerate_denominator)
+            .finish()
+    }
+}
+
+impl CameraSpec {
+    pub fn from_ll(spec: SDL_CameraSpec) -> CameraSpec {
+        CameraSpec {
+            format: unsafe { PixelFormat::from_ll(spec.format) },
+            colorspace: spec.colorspace,
+            width: spec.width,
+            height: spec.height,
+            framerate_numerator: spec.framerate_numerator,
+            framerate_denominator: spec.framerate_denominator,
+        }
+    }
+
+    pub fn to_ll(&self) -> SDL_CameraSpec {
+        SDL_CameraSpec {
+            format: self.format.into(),
+            colorspace: self.colorspace,
+            width: self.width,
+            height: self.height,
+            framerate_numerator: self.framerate_numerator,
+            framerate_denominator: self.framerate_denominator,
+        }
+    }
+
+    /// The frame rate in frames per second, or `0.0` if the spec does not specify one.
+    pub fn fps(&self) -> f32 {
+        if self.framerate_denominator == 0 {
+            0.0
+        } else {
+            self.framerate_numerator as f32 / self.framerate_denominator as f32
+        }
+    }
+}
+
+/// Wrapper around the `SDL_Camera` object
+pub struct Camera {
+    subsystem: CameraSubsystem,
+    raw: *mut SDL_Camera,
+}
+
+impl Camera {
+    #[inline]
+    pub const fn subsystem(&self) -> &CameraSubsystem {
+        &self.subsystem
+    }
+
+    #[inline]
+    pub const fn raw(&self) -> *mut SDL_Camera {
+        self.raw
+    }
+
+    #[doc(alias = "SDL_GetCameraID")]
+    pub fn id(&self) -> CameraId {
+        unsafe { sys::camera::SDL_GetCameraID(self.raw) }
+    }
+
+    /// Query if camera access has been approved by the user.
+    ///
+    /// Instead of polling this, one can also wait for an
+    /// [`Event::CameraDeviceApproved`](crate::event::Event::CameraDeviceApproved) or
+    /// [`Event::CameraDeviceDenied`](crate::event::Event::CameraDeviceDenied) event.
+    #[doc(alias = "SDL_GetCameraPermissionState")]
+    pub fn permission_state(&self) -> CameraPermission {
+        match unsafe { sys::camera::SDL_GetCameraPermissionState(self.raw) } {
+            0 => CameraPermission::Pending,
+            x if x > 0 => CameraPermission::Approved,
+            _ => CameraPermission::Denied,
+        }
+    }
+
+    /// Get the spec that the camera is using when generating frames.
+    ///
+    /// This fails while the user has not yet approved access to the camera.
+    #[doc(alias = "SDL_GetCameraFormat")]
+    pub fn format(&self) -> Result<CameraSpec, Error> {
+        let mut spec = SDL_CameraSpec::default();
+        if unsafe { sys::camera::SDL_GetCameraFormat(self.raw, &mut spec) } {
+            Ok(CameraSpec::from_ll(spec))
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Acquire a frame, if one is available.
+    ///
+    /// The frame is released back to the camera when the returned [`CameraFrame`] is dropped.
+    /// Frames should be released as soon as possible, otherwise the system may stop providing
+    /// more of them.
+    #[doc(alias = "SDL_AcquireCameraFrame")]
+    pub fn acquire_frame(&self) -> Option<CameraFrame<'_>> {
+        let mut timestamp_ns = 0;
+        let raw = unsafe { sys::camera::SDL_AcquireCameraFrame(self.raw, &mut timestamp_ns) };
+        if raw.is_null() {
+            None
+        } else {
+            Some(CameraFrame {
+                camera: self,
+                raw,
+                timestamp_ns,
+            })
+        }
+    }
+}
+
+impl Drop for Camera {
+    #[doc(alias = "SDL_CloseCamera")]
+    fn drop(&mut self) {
+        unsafe { sys::camera::SDL_CloseCamera(self.raw) }
+    }
+}
+
+/// A frame of video acquired from a [`Camera`].
+///
+/// Derefs to the [`SurfaceRef`] holding the image data, so it can be passed directly to
+/// [`TextureCreator::create_texture_from_surface`](crate::render::TextureCreator::create_texture_from_surface).
+pub struct CameraFrame<'a> {
+    camera: &'a Camera,
+    raw: *mut sys::surface::SDL_Surface,
+    timestamp_ns: u64,
+}
+
+impl CameraFrame<'_> {
+    /// The time the frame was captured, in nanoseconds.
+    #[inline]
+    pub fn timestamp_ns(&self) -> u64 {
+        self.timestamp_ns
+    }
+}
+
+impl Deref for CameraFrame<'_> {
+    type Target = SurfaceRef;
+
+    #[inline]
+    fn deref(&self) -> &SurfaceRef {
+        unsafe { SurfaceRef::from_ll(self.raw) }
+    }
+}
+
+impl AsRef<SurfaceRef> for CameraFrame<'_> {
+    #[inline]
+    fn as_ref(&self) -> &SurfaceRef {
+        self
+    }
+}
+
+impl Drop for CameraFrame<'_> {
+    #[doc(alias = "SDL_ReleaseCameraFrame")]
+    fn drop(&mut self) {
+        unsafe { sys::camera::SDL_ReleaseCameraFrame(self.camera.raw, self.raw) }
+    }
+}
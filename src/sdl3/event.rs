@@ -3,42 +3,61 @@ Event Handling
  */
 
 use std::borrow::ToOwned;
-use std::collections::HashMap;
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::convert::TryFrom;
-use std::ffi::CStr;
+use std::ffi::{CStr, CString};
 use std::iter::FromIterator;
 use std::marker::PhantomData;
 use std::mem;
 use std::mem::transmute;
 use std::ptr;
-use std::sync::Mutex;
+use std::slice;
+use std::sync::{Mutex, MutexGuard};
 
 use crate::gamepad;
 use crate::gamepad::{Axis, Button};
 use crate::get_error;
 use crate::joystick;
-use crate::joystick::HatState;
+use crate::joystick::{HatState, PowerLevel};
 use crate::keyboard;
 use crate::keyboard::Keycode;
 use crate::keyboard::Mod;
 use crate::keyboard::Scancode;
 use crate::mouse;
 use crate::mouse::{MouseButton, MouseState, MouseWheelDirection};
+use crate::pen;
+use crate::pen::{PenAxis, PenInputFlags};
 use crate::sys;
 use crate::sys::events::SDL_EventFilter;
 use crate::video::{Display, Orientation};
 use crate::Error;
+use libc::c_char;
 use libc::c_int;
 use libc::c_void;
 use sys::events::{
-    SDL_DisplayEvent, SDL_EventType, SDL_GamepadAxisEvent, SDL_GamepadButtonEvent,
-    SDL_GamepadDeviceEvent, SDL_JoyAxisEvent, SDL_JoyButtonEvent, SDL_JoyDeviceEvent,
-    SDL_JoyHatEvent, SDL_KeyboardEvent, SDL_MouseButtonEvent, SDL_MouseMotionEvent,
-    SDL_MouseWheelEvent,
+    SDL_CameraDeviceEvent, SDL_DisplayEvent, SDL_EventType, SDL_GamepadAxisEvent,
+    SDL_GamepadButtonEvent, SDL_GamepadDeviceEvent, SDL_JoyAxisEvent, SDL_JoyButtonEvent,
+    SDL_JoyDeviceEvent, SDL_JoyHatEvent, SDL_KeyboardEvent, SDL_MouseButtonEvent,
+    SDL_MouseMotionEvent, SDL_MouseWheelEvent, SDL_PenAxisEvent, SDL_PenButtonEvent,
+    SDL_PenMotionEvent, SDL_PenProximityEvent, SDL_PenTouchEvent,
 };
 use sys::everything::SDL_DisplayOrientation;
 use sys::stdinc::Uint16;
 
+#[cfg(feature = "async")]
+use futures_core::Stream;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+#[cfg(feature = "async")]
+use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "async")]
+use std::sync::Arc;
+#[cfg(feature = "async")]
+use std::task::{Context, Poll, Waker};
+#[cfg(feature = "async")]
+use std::time::Duration;
+
 struct CustomEventTypeMaps {
     sdl_id_to_type_id: HashMap<u32, ::std::any::TypeId>,
     type_id_to_sdl_id: HashMap<::std::any::TypeId, u32>,
@@ -56,19 +75,185 @@ impl CustomEventTypeMaps {
 lazy_static! {
     static ref CUSTOM_EVENT_TYPES: Mutex<CustomEventTypeMaps> =
         Mutex::new(CustomEventTypeMaps::new());
+    static ref EVENT_FILTER: Mutex<Option<EventFilter>> = Mutex::new(None);
+    /// Serializes replacing the event filter. Unlike `EVENT_FILTER`, this may be held while
+    /// calling into SDL, since the filter itself never takes it.
+    static ref EVENT_FILTER_SETTER: Mutex<()> = Mutex::new(());
+    /// The memory of pushed events, freed when they are polled, see [`EventMemory`].
+    static ref QUEUED_EVENT_MEMORY: Mutex<HashMap<usize, QueuedMemory>> =
+        Mutex::new(HashMap::new());
+}
+
+/// How many conversions with [`Event::to_ll`] keep their memory on a thread that doesn't poll.
+const MAX_TEMPORARY_EVENTS: usize = 64;
+
+thread_local! {
+    /// The memory of the last events with strings converted with [`Event::to_ll`] on this
+    /// thread, freed the next time this thread polls events.
+    static TEMPORARY_EVENT_MEMORY: RefCell<VecDeque<Vec<EventMemory>>> =
+        const { RefCell::new(VecDeque::new()) };
+    /// Set while the event filter runs on this thread, as `EVENT_FILTER` isn't reentrant.
+    static IN_EVENT_FILTER: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Owns what a string field of a raw event points to.
+///
+/// This works like SDL's temporary memory, which it uses for the events it creates itself but
+/// which isn't available to applications: the memory of an event converted with
+/// [`Event::to_ll`] is freed the next time the converting thread polls events, or once it
+/// converted [`MAX_TEMPORARY_EVENTS`] more events with strings. The memory of an
+/// event pushed with [`EventSender::push_event`] is owned by the queue instead, and freed once
+/// the event is polled or flushed.
+enum EventMemory {
+    String(CString),
+    /// An array of string pointers, stored as addresses so that it is `Send`.
+    List {
+        pointers: Box<[usize]>,
+        _strings: Vec<CString>,
+    },
+}
+
+impl EventMemory {
+    fn address(&self) -> usize {
+        match self {
+            EventMemory::String(s) => s.as_ptr() as usize,
+            EventMemory::List { pointers, .. } => pointers.as_ptr() as usize,
+        }
+    }
+}
+
+struct QueuedMemory {
+    _memory: EventMemory,
+    /// `false` until `SDL_PushEvent` returned, as the event may not be in the queue yet.
+    pushed: bool,
+}
+
+/// Returns a pointer to a nul-terminated copy of `s` for the string fields of raw events, which
+/// stays valid as long as `memory`. Returns `None` if `s` contains a nul byte.
+///
+/// Without `memory`, only checks `s` and returns a null pointer.
+fn event_string(memory: Option<&mut Vec<EventMemory>>, s: &str) -> Option<*const c_char> {
+    let Some(memory) = memory else {
+        return (!s.contains('\0')).then(ptr::null);
+    };
+    let s = CString::new(s).ok()?;
+    let ptr = s.as_ptr();
+    memory.push(EventMemory::String(s));
+    Some(ptr)
+}
+
+/// Like [`event_string`], for arrays of strings. Returns a null pointer for an empty array.
+fn event_string_list(
+    memory: Option<&mut Vec<EventMemory>>,
+    strings: &[String],
+) -> Option<*const *const c_char> {
+    let Some(memory) = memory.filter(|_| !strings.is_empty()) else {
+        return strings.iter().all(|s| !s.contains('\0')).then(ptr::null);
+    };
+    let strings = strings
+        .iter()
+        .map(|s| CString::new(s.as_str()).ok())
+        .collect::<Option<Vec<_>>>()?;
+    let pointers: Box<[usize]> = strings.iter().map(|s| s.as_ptr() as usize).collect();
+    let ptr = pointers.as_ptr() as *const *const c_char;
+    memory.push(EventMemory::List {
+        pointers,
+        _strings: strings,
+    });
+    Some(ptr)
+}
+
+/// The addresses of the memory the string fields of `raw` point to.
+fn event_memory_addresses(raw: &sys::events::SDL_Event) -> [usize; 2] {
+    unsafe {
+        match sys::events::SDL_EventType(raw.r#type) {
+            sys::events::SDL_EVENT_TEXT_EDITING => [raw.edit.text as usize, 0],
+            sys::events::SDL_EVENT_TEXT_EDITING_CANDIDATES => {
+                [raw.edit_candidates.candidates as usize, 0]
+            }
+            sys::events::SDL_EVENT_TEXT_INPUT => [raw.text.text as usize, 0],
+            sys::events::SDL_EVENT_DROP_BEGIN
+            | sys::events::SDL_EVENT_DROP_FILE
+            | sys::events::SDL_EVENT_DROP_TEXT
+            | sys::events::SDL_EVENT_DROP_COMPLETE
+            | sys::events::SDL_EVENT_DROP_POSITION => {
+                [raw.drop.source as usize, raw.drop.data as usize]
+            }
+            _ => [0, 0],
+        }
+    }
+}
+
+fn queued_event_memory() -> MutexGuard<'static, HashMap<usize, QueuedMemory>> {
+    QUEUED_EVENT_MEMORY
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+}
+
+/// Frees the memory of a polled event, and the temporary memory of this thread.
+fn free_event_memory(raw: &sys::events::SDL_Event) {
+    TEMPORARY_EVENT_MEMORY.with(|memory| memory.borrow_mut().clear());
+    let addresses = event_memory_addresses(raw);
+    if addresses != [0, 0] {
+        let mut queued = queued_event_memory();
+        for address in addresses {
+            queued.remove(&address);
+        }
+    }
+}
+
+/// Frees the memory of pushed events that are no longer in the queue, e.g. after flushing.
+///
+/// Must be called on the main thread, so that no event is being polled concurrently.
+fn free_flushed_event_memory() {
+    let mut queued = queued_event_memory();
+    if queued.is_empty() {
+        return;
+    }
+    let mut events = Vec::<sys::events::SDL_Event>::new();
+    let referenced: HashSet<usize> = unsafe {
+        let count = sys::events::SDL_PeepEvents(
+            ptr::null_mut(),
+            0,
+            sys::events::SDL_PEEKEVENT,
+            sys::events::SDL_EVENT_FIRST.into(),
+            sys::events::SDL_EVENT_LAST.into(),
+        );
+        if count < 0 {
+            return;
+        }
+        events.reserve(count as usize);
+        let count = sys::events::SDL_PeepEvents(
+            events.as_mut_ptr(),
+            count,
+            sys::events::SDL_PEEKEVENT,
+            sys::events::SDL_EVENT_FIRST.into(),
+            sys::events::SDL_EVENT_LAST.into(),
+        );
+        events.set_len(count.max(0) as usize);
+        events.iter().flat_map(event_memory_addresses).collect()
+    };
+    queued.retain(|address, memory| !memory.pushed || referenced.contains(address));
 }
 
+/// Type alias for the closure installed by [`EventSubsystem::set_event_filter`].
+///
+/// [`EventSubsystem::set_event_filter`]: crate::EventSubsystem::set_event_filter
+pub type EventFilter = Box<dyn FnMut(&Event) -> bool + Send + 'static>;
+
 impl crate::EventSubsystem {
     /// Removes all events in the event queue that match the specified event type.
     #[doc(alias = "SDL_FlushEvent")]
     pub fn flush_event(&self, event_type: EventType) {
         unsafe { sys::events::SDL_FlushEvent(event_type.into()) };
+        free_flushed_event_memory();
     }
 
     /// Removes all events in the event queue that match the specified type range.
     #[doc(alias = "SDL_FlushEvents")]
     pub fn flush_events(&self, min_type: u32, max_type: u32) {
         unsafe { sys::events::SDL_FlushEvents(min_type, max_type) };
+        free_flushed_event_memory();
     }
 
     /// Reads the events at the front of the event queue, until the maximum amount
@@ -265,6 +450,119 @@ impl crate::EventSubsystem {
     ) -> EventWatch<'a, CB> {
         EventWatch::add(callback)
     }
+
+    /// Installs a filter that decides whether events are added to the event queue, replacing
+    /// any previous one.
+    ///
+    /// The filter is called for every event before it is queued, and the event is dropped if
+    /// it returns `false`. It may run on any thread that pushes events, so it must be `Send`,
+    /// should be quick, and **must not panic!** It also must not call any of the event filter
+    /// functions itself. Events it pushes are queued without being filtered. Events already in
+    /// the queue are not affected, see [`filter_events`](Self::filter_events) for those.
+    ///
+    /// Returns the previously installed filter, if any.
+    ///
+    /// # Example: drop mouse motion events
+    /// ```no_run
+    /// use sdl3::event::Event;
+    ///
+    /// let sdl = sdl3::init().unwrap();
+    /// let ev = sdl.event().unwrap();
+    ///
+    /// ev.set_event_filter(|event| !matches!(event, Event::MouseMotion { .. }));
+    /// ```
+    #[doc(alias = "SDL_SetEventFilter")]
+    pub fn set_event_filter<F>(&self, filter: F) -> Option<EventFilter>
+    where
+        F: FnMut(&Event) -> bool + Send + 'static,
+    {
+        let _setter = EVENT_FILTER_SETTER
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        // SDL calls the filter with its own lock held, so `EVENT_FILTER` must not be held
+        // while calling into SDL.
+        let previous = EVENT_FILTER
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .replace(Box::new(filter));
+        unsafe { sys::events::SDL_SetEventFilter(Some(event_filter_marshall), ptr::null_mut()) };
+        previous
+    }
+
+    /// Returns `true` if an event filter is installed, either with
+    /// [`set_event_filter`](Self::set_event_filter) or directly through SDL.
+    ///
+    /// The filter itself can't be returned: it stays installed and may be running on another
+    /// thread at any time. Use [`clear_event_filter`](Self::clear_event_filter) to get it back.
+    #[doc(alias = "SDL_GetEventFilter")]
+    pub fn has_event_filter(&self) -> bool {
+        let mut filter: SDL_EventFilter = None;
+        let mut userdata = ptr::null_mut();
+        unsafe { sys::events::SDL_GetEventFilter(&mut filter, &mut userdata) }
+    }
+
+    /// Removes the event filter and returns it, if one was installed with
+    /// [`set_event_filter`](Self::set_event_filter).
+    #[doc(alias = "SDL_SetEventFilter")]
+    pub fn clear_event_filter(&self) -> Option<EventFilter> {
+        let _setter = EVENT_FILTER_SETTER
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        unsafe { sys::events::SDL_SetEventFilter(None, ptr::null_mut()) };
+        EVENT_FILTER
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take()
+    }
+
+    /// Runs `filter` once on every event in the queue, removing those for which it returns
+    /// `false`.
+    ///
+    /// # Example: discard queued key presses
+    /// ```no_run
+    /// use sdl3::event::Event;
+    ///
+    /// let sdl = sdl3::init().unwrap();
+    /// let ev = sdl.event().unwrap();
+    ///
+    /// ev.filter_events(|event| !matches!(event, Event::KeyDown { .. }));
+    /// ```
+    #[doc(alias = "SDL_FilterEvents")]
+    pub fn filter_events<F>(&self, mut filter: F)
+    where
+        F: FnMut(&Event) -> bool,
+    {
+        extern "C" fn marshall<F: FnMut(&Event) -> bool>(
+            user_data: *mut c_void,
+            event: *mut sys::events::SDL_Event,
+        ) -> bool {
+            let f: &mut F = unsafe { &mut *(user_data as *mut F) };
+            f(&Event::from_ll(unsafe { *event }))
+        }
+
+        unsafe {
+            sys::events::SDL_FilterEvents(Some(marshall::<F>), &mut filter as *mut F as *mut c_void)
+        };
+        free_flushed_event_memory();
+    }
+}
+
+extern "C" fn event_filter_marshall(
+    _user_data: *mut c_void,
+    event: *mut sys::events::SDL_Event,
+) -> bool {
+    // An event pushed by the filter itself passes, instead of deadlocking on `EVENT_FILTER`.
+    if IN_EVENT_FILTER.with(|in_filter| in_filter.replace(true)) {
+        return true;
+    }
+    let mut filter = EVENT_FILTER.lock().unwrap_or_else(|e| e.into_inner());
+    let keep = match filter.as_mut() {
+        Some(f) => f(&Event::from_ll(unsafe { *event })),
+        None => true,
+    };
+    drop(filter);
+    IN_EVENT_FILTER.with(|in_filter| in_filter.set(false));
+    keep
 }
 
 /// Types of events that can be delivered.
@@ -280,6 +578,8 @@ pub enum EventType {
     AppDidEnterBackground = sys::events::SDL_EVENT_DID_ENTER_BACKGROUND.0,
     AppWillEnterForeground = sys::events::SDL_EVENT_WILL_ENTER_FOREGROUND.0,
     AppDidEnterForeground = sys::events::SDL_EVENT_DID_ENTER_FOREGROUND.0,
+    LocaleChanged = sys::events::SDL_EVENT_LOCALE_CHANGED.0,
+    SystemThemeChanged = sys::events::SDL_EVENT_SYSTEM_THEME_CHANGED.0,
 
     DisplayAdded = sys::events::SDL_EVENT_DISPLAY_ADDED.0,
     DisplayRemoved = sys::events::SDL_EVENT_DISPLAY_REMOVED.0,
@@ -311,12 +611,17 @@ pub enum EventType {
     KeyDown = sys::events::SDL_EVENT_KEY_DOWN.0,
     KeyUp = sys::events::SDL_EVENT_KEY_UP.0,
     TextEditing = sys::events::SDL_EVENT_TEXT_EDITING.0,
+    TextEditingCandidates = sys::events::SDL_EVENT_TEXT_EDITING_CANDIDATES.0,
     TextInput = sys::events::SDL_EVENT_TEXT_INPUT.0,
+    KeyboardAdded = sys::events::SDL_EVENT_KEYBOARD_ADDED.0,
+    KeyboardRemoved = sys::events::SDL_EVENT_KEYBOARD_REMOVED.0,
 
     MouseMotion = sys::events::SDL_EVENT_MOUSE_MOTION.0,
     MouseButtonDown = sys::events::SDL_EVENT_MOUSE_BUTTON_DOWN.0,
     MouseButtonUp = sys::events::SDL_EVENT_MOUSE_BUTTON_UP.0,
     MouseWheel = sys::events::SDL_EVENT_MOUSE_WHEEL.0,
+    MouseAdded = sys::events::SDL_EVENT_MOUSE_ADDED.0,
+    MouseRemoved = sys::events::SDL_EVENT_MOUSE_REMOVED.0,
 
     JoyAxisMotion = sys::events::SDL_EVENT_JOYSTICK_AXIS_MOTION.0,
     JoyHatMotion = sys::events::SDL_EVENT_JOYSTICK_HAT_MOTION.0,
@@ -324,6 +629,7 @@ pub enum EventType {
     JoyButtonUp = sys::events::SDL_EVENT_JOYSTICK_BUTTON_UP.0,
     JoyDeviceAdded = sys::events::SDL_EVENT_JOYSTICK_ADDED.0,
     JoyDeviceRemoved = sys::events::SDL_EVENT_JOYSTICK_REMOVED.0,
+    JoyBatteryUpdated = sys::events::SDL_EVENT_JOYSTICK_BATTERY_UPDATED.0,
 
     ControllerAxisMotion = sys::events::SDL_EVENT_GAMEPAD_AXIS_MOTION.0,
     ControllerButtonDown = sys::events::SDL_EVENT_GAMEPAD_BUTTON_DOWN.0,
@@ -350,6 +656,20 @@ pub enum EventType {
     AudioDeviceAdded = sys::events::SDL_EVENT_AUDIO_DEVICE_ADDED.0,
     AudioDeviceRemoved = sys::events::SDL_EVENT_AUDIO_DEVICE_REMOVED.0,
 
+    CameraDeviceAdded = sys::events::SDL_EVENT_CAMERA_DEVICE_ADDED.0,
+    CameraDeviceRemoved = sys::events::SDL_EVENT_CAMERA_DEVICE_REMOVED.0,
+    CameraDeviceApproved = sys::events::SDL_EVENT_CAMERA_DEVICE_APPROVED.0,
+    CameraDeviceDenied = sys::events::SDL_EVENT_CAMERA_DEVICE_DENIED.0,
+
+    PenProximityIn = sys::events::SDL_EVENT_PEN_PROXIMITY_IN.0,
+    PenProximityOut = sys::events::SDL_EVENT_PEN_PROXIMITY_OUT.0,
+    PenDown = sys::events::SDL_EVENT_PEN_DOWN.0,
+    PenUp = sys::events::SDL_EVENT_PEN_UP.0,
+    PenButtonDown = sys::events::SDL_EVENT_PEN_BUTTON_DOWN.0,
+    PenButtonUp = sys::events::SDL_EVENT_PEN_BUTTON_UP.0,
+    PenMotion = sys::events::SDL_EVENT_PEN_MOTION.0,
+    PenAxis = sys::events::SDL_EVENT_PEN_AXIS.0,
+
     RenderTargetsReset = sys::events::SDL_EVENT_RENDER_TARGETS_RESET.0,
     RenderDeviceReset = sys::events::SDL_EVENT_RENDER_DEVICE_RESET.0,
 
@@ -385,6 +705,8 @@ impl TryFrom<u32> for EventType {
             SDL_EVENT_DID_ENTER_BACKGROUND => AppDidEnterBackground,
             SDL_EVENT_WILL_ENTER_FOREGROUND => AppWillEnterForeground,
             SDL_EVENT_DID_ENTER_FOREGROUND => AppDidEnterForeground,
+            SDL_EVENT_LOCALE_CHANGED => LocaleChanged,
+            SDL_EVENT_SYSTEM_THEME_CHANGED => SystemThemeChanged,
 
             SDL_EVENT_DISPLAY_ADDED => DisplayAdded,
             SDL_EVENT_DISPLAY_REMOVED => DisplayRemoved,
@@ -412,12 +734,17 @@ impl TryFrom<u32> for EventType {
             SDL_EVENT_KEY_DOWN => KeyDown,
             SDL_EVENT_KEY_UP => KeyUp,
             SDL_EVENT_TEXT_EDITING => TextEditing,
+            SDL_EVENT_TEXT_EDITING_CANDIDATES => TextEditingCandidates,
             SDL_EVENT_TEXT_INPUT => TextInput,
+            SDL_EVENT_KEYBOARD_ADDED => KeyboardAdded,
+            SDL_EVENT_KEYBOARD_REMOVED => KeyboardRemoved,
 
             SDL_EVENT_MOUSE_MOTION => MouseMotion,
             SDL_EVENT_MOUSE_BUTTON_DOWN => MouseButtonDown,
             SDL_EVENT_MOUSE_BUTTON_UP => MouseButtonUp,
             SDL_EVENT_MOUSE_WHEEL => MouseWheel,
+            SDL_EVENT_MOUSE_ADDED => MouseAdded,
+            SDL_EVENT_MOUSE_REMOVED => MouseRemoved,
 
             SDL_EVENT_JOYSTICK_AXIS_MOTION => JoyAxisMotion,
             SDL_EVENT_JOYSTICK_HAT_MOTION => JoyHatMotion,
@@ -425,6 +752,7 @@ impl TryFrom<u32> for EventType {
             SDL_EVENT_JOYSTICK_BUTTON_UP => JoyButtonUp,
             SDL_EVENT_JOYSTICK_ADDED => JoyDeviceAdded,
             SDL_EVENT_JOYSTICK_REMOVED => JoyDeviceRemoved,
+            SDL_EVENT_JOYSTICK_BATTERY_UPDATED => JoyBatteryUpdated,
 
             SDL_EVENT_GAMEPAD_AXIS_MOTION => ControllerAxisMotion,
             SDL_EVENT_GAMEPAD_BUTTON_DOWN => ControllerButtonDown,
@@ -451,6 +779,20 @@ impl TryFrom<u32> for EventType {
             SDL_EVENT_AUDIO_DEVICE_ADDED => AudioDeviceAdded,
             SDL_EVENT_AUDIO_DEVICE_REMOVED => AudioDeviceRemoved,
 
+            SDL_EVENT_CAMERA_DEVICE_ADDED => CameraDeviceAdded,
+            SDL_EVENT_CAMERA_DEVICE_REMOVED => CameraDeviceRemoved,
+            SDL_EVENT_CAMERA_DEVICE_APPROVED => CameraDeviceApproved,
+            SDL_EVENT_CAMERA_DEVICE_DENIED => CameraDeviceDenied,
+
+            SDL_EVENT_PEN_PROXIMITY_IN => PenProximityIn,
+            SDL_EVENT_PEN_PROXIMITY_OUT => PenProximityOut,
+            SDL_EVENT_PEN_DOWN => PenDown,
+            SDL_EVENT_PEN_UP => PenUp,
+            SDL_EVENT_PEN_BUTTON_DOWN => PenButtonDown,
+            SDL_EVENT_PEN_BUTTON_UP => PenButtonUp,
+            SDL_EVENT_PEN_MOTION => PenMotion,
+            SDL_EVENT_PEN_AXIS => PenAxis,
+
             SDL_EVENT_RENDER_TARGETS_RESET => RenderTargetsReset,
             SDL_EVENT_RENDER_DEVICE_RESET => RenderDeviceReset,
 
@@ -464,6 +806,7 @@ impl TryFrom<u32> for EventType {
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 /// An enum of display events.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DisplayEvent {
     None,
     Orientation(Orientation),
@@ -500,15 +843,15 @@ impl DisplayEvent {
         }
     }
 
-    fn to_ll(self) -> (u32, i32) {
-        match self {
+    fn to_ll(self) -> Option<(u32, i32)> {
+        Some(match self {
             DisplayEvent::Orientation(orientation) => (
                 sys::events::SDL_EVENT_DISPLAY_ORIENTATION.into(),
                 orientation.to_ll().into(),
             ),
             DisplayEvent::Added => (sys::events::SDL_EVENT_DISPLAY_ADDED.into(), 0),
             DisplayEvent::Removed => (sys::events::SDL_EVENT_DISPLAY_REMOVED.into(), 0),
-            DisplayEvent::None => panic!("DisplayEvent::None cannot be converted"),
+            DisplayEvent::None => return None,
             DisplayEvent::Moved => (sys::events::SDL_EVENT_DISPLAY_MOVED.into(), 0),
             DisplayEvent::DesktopModeChanged => (
                 sys::events::SDL_EVENT_DISPLAY_DESKTOP_MODE_CHANGED.into(),
@@ -522,7 +865,7 @@ impl DisplayEvent {
                 sys::events::SDL_EVENT_DISPLAY_CONTENT_SCALE_CHANGED.into(),
                 0,
             ),
-        }
+        })
     }
 
     pub fn is_same_kind_as(&self, other: &DisplayEvent) -> bool {
@@ -538,6 +881,7 @@ impl DisplayEvent {
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 /// An enum of window events.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WindowEvent {
     None,
     Shown,
@@ -587,9 +931,9 @@ impl WindowEvent {
         }
     }
 
-    fn to_ll(self) -> (EventType, i32, i32) {
-        match self {
-            WindowEvent::None => panic!("Cannot convert WindowEvent::None"),
+    fn to_ll(self) -> Option<(EventType, i32, i32)> {
+        Some(match self {
+            WindowEvent::None => return None,
             WindowEvent::Shown => (EventType::WindowShown, 0, 0),
             WindowEvent::Hidden => (EventType::WindowHidden, 0, 0),
             WindowEvent::Exposed => (EventType::WindowExposed, 0, 0),
@@ -607,7 +951,7 @@ impl WindowEvent {
             WindowEvent::HitTest(d1, d2) => (EventType::WindowHitTest, d1, d2),
             WindowEvent::ICCProfChanged => (EventType::WindowICCProfileChanged, 0, 0),
             WindowEvent::DisplayChanged(d1) => (EventType::WindowDisplayChanged, d1, 0),
-        }
+        })
     }
 
     pub fn is_same_kind_as(&self, other: &WindowEvent) -> bool {
@@ -636,6 +980,7 @@ impl WindowEvent {
 }
 
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Different event types.
 pub enum Event {
     Quit {
@@ -659,6 +1004,15 @@ pub enum Event {
     AppDidEnterForeground {
         timestamp: u64,
     },
+    /// The user's preferred locales changed, see [`preferred_locales`](crate::locale::preferred_locales).
+    LocaleChanged {
+        timestamp: u64,
+    },
+    /// The system theme changed, see
+    /// [`VideoSubsystem::get_system_theme`](crate::VideoSubsystem::get_system_theme).
+    SystemThemeChanged {
+        timestamp: u64,
+    },
 
     Window {
         timestamp: u64,
@@ -696,12 +1050,35 @@ pub enum Event {
         length: i32,
     },
 
+    /// The candidate list of an IME composition, only sent if the app draws the candidates
+    /// itself, see the `SDL_HINT_IME_IMPLEMENTED_UI` hint.
+    TextEditingCandidates {
+        timestamp: u64,
+        window_id: u32,
+        /// Empty if there are no candidates.
+        candidates: Vec<String>,
+        selected_candidate: Option<usize>,
+        /// `true` if the list is laid out horizontally, `false` if vertically.
+        horizontal: bool,
+    },
+
     TextInput {
         timestamp: u64,
         window_id: u32,
         text: String,
     },
 
+    KeyboardAdded {
+        timestamp: u64,
+        /// The keyboard's id, see [`KeyboardUtil::keyboards`](crate::keyboard::KeyboardUtil::keyboards)
+        which: u32,
+    },
+    KeyboardRemoved {
+        timestamp: u64,
+        /// The keyboard's id, see [`KeyboardUtil::keyboards`](crate::keyboard::KeyboardUtil::keyboards)
+        which: u32,
+    },
+
     MouseMotion {
         timestamp: u64,
         window_id: u32,
@@ -743,6 +1120,17 @@ pub enum Event {
         mouse_y: f32,
     },
 
+    MouseAdded {
+        timestamp: u64,
+        /// The mouse's id, see [`MouseUtil::mice`](crate::mouse::MouseUtil::mice)
+        which: u32,
+    },
+    MouseRemoved {
+        timestamp: u64,
+        /// The mouse's id, see [`MouseUtil::mice`](crate::mouse::MouseUtil::mice)
+        which: u32,
+    },
+
     JoyAxisMotion {
         timestamp: u64,
         /// The joystick's `id`
@@ -782,6 +1170,15 @@ pub enum Event {
         /// The joystick's `id`
         which: u32,
     },
+    /// The battery state of a joystick changed.
+    JoyBatteryUpdated {
+        timestamp: u64,
+        /// The joystick's `id`
+        which: u32,
+        state: PowerLevel,
+        /// Percentage of battery life left, between 0 and 100, or -1 if unknown.
+        percent: i32,
+    },
 
     ControllerAxisMotion {
         timestamp: u64,
@@ -963,6 +1360,111 @@ pub enum Event {
         iscapture: bool,
     },
 
+    CameraDeviceAdded {
+        timestamp: u64,
+        /// The camera's `CameraId`
+        which: u32,
+    },
+    CameraDeviceRemoved {
+        timestamp: u64,
+        /// The camera's `CameraId`
+        which: u32,
+    },
+    /// The user approved access to an opened camera.
+    CameraDeviceApproved {
+        timestamp: u64,
+        /// The camera's `CameraId`
+        which: u32,
+    },
+    /// The user denied access to an opened camera.
+    CameraDeviceDenied {
+        timestamp: u64,
+        /// The camera's `CameraId`
+        which: u32,
+    },
+
+    /// A pen came into proximity of the tablet.
+    PenProximityIn {
+        timestamp: u64,
+        window_id: u32,
+        /// The pen's `PenId`
+        which: u32,
+    },
+    /// A pen left the proximity of the tablet, its `PenId` is no longer valid.
+    PenProximityOut {
+        timestamp: u64,
+        window_id: u32,
+        /// The pen's `PenId`
+        which: u32,
+    },
+    /// The tip of a pen touched the tablet.
+    PenDown {
+        timestamp: u64,
+        window_id: u32,
+        /// The pen's `PenId`
+        which: u32,
+        state: PenInputFlags,
+        x: f32,
+        y: f32,
+        /// `true` if the eraser end of the pen is used.
+        eraser: bool,
+    },
+    /// The tip of a pen was lifted from the tablet.
+    PenUp {
+        timestamp: u64,
+        window_id: u32,
+        /// The pen's `PenId`
+        which: u32,
+        state: PenInputFlags,
+        x: f32,
+        y: f32,
+        /// `true` if the eraser end of the pen is used.
+        eraser: bool,
+    },
+    PenButtonDown {
+        timestamp: u64,
+        window_id: u32,
+        /// The pen's `PenId`
+        which: u32,
+        state: PenInputFlags,
+        x: f32,
+        y: f32,
+        /// The button index, starting at 1.
+        button: u8,
+    },
+    PenButtonUp {
+        timestamp: u64,
+        window_id: u32,
+        /// The pen's `PenId`
+        which: u32,
+        state: PenInputFlags,
+        x: f32,
+        y: f32,
+        /// The button index, starting at 1.
+        button: u8,
+    },
+    PenMotion {
+        timestamp: u64,
+        window_id: u32,
+        /// The pen's `PenId`
+        which: u32,
+        state: PenInputFlags,
+        x: f32,
+        y: f32,
+    },
+    /// The value of a pen axis, like pressure or tilt, changed.
+    PenAxis {
+        timestamp: u64,
+        window_id: u32,
+        /// The pen's `PenId`
+        which: u32,
+        state: PenInputFlags,
+        x: f32,
+        y: f32,
+        axis: PenAxis,
+        value: f32,
+    },
+
     RenderTargetsReset {
         timestamp: u64,
     },
@@ -975,7 +1477,10 @@ pub enum Event {
         window_id: u32,
         type_: u32,
         code: i32,
+        /// Not serialized, deserialized user events carry null pointers.
+        #[cfg_attr(feature = "serde", serde(skip, default = "ptr::null_mut"))]
         data1: *mut c_void,
+        #[cfg_attr(feature = "serde", serde(skip, default = "ptr::null_mut"))]
         data2: *mut c_void,
     },
 
@@ -1005,8 +1510,46 @@ unsafe impl Sync for Event {}
 // This would honestly be nice if it took &self instead of self,
 // but Event::User's raw pointers kind of removes that possibility.
 impl Event {
-    fn to_ll(&self) -> Option<sys::events::SDL_Event> {
-        let mut ret = mem::MaybeUninit::uninit();
+    /// Converts the event to its raw SDL form, e.g. to push it with SDL functions directly.
+    ///
+    /// Converting back with [`from_ll`](Self::from_ll) gives an equal event, except for
+    /// [`Event::Unknown`] with the `type_` of an event this library knows, which converts back
+    /// to that event. Returns `None` for events that have no raw form: the gesture events,
+    /// which SDL3 doesn't have anymore, [`WindowEvent::None`], [`DisplayEvent::None`], key
+    /// events without a keycode or scancode, and events with strings that contain a nul byte.
+    ///
+    /// Strings are copied into temporary memory, like SDL does for the events it creates: it
+    /// is freed the next time this thread polls events, e.g. with
+    /// [`EventPump::poll_event`](crate::EventPump::poll_event). Only the last 64 events with
+    /// strings converted on a thread keep their memory, so a thread that never polls doesn't
+    /// pile up memory, and older raw events must not be used anymore. Push events with
+    /// [`EventSender::push_event`] instead of converting them, so that the queue keeps their
+    /// strings alive until they are polled.
+    pub fn to_ll(&self) -> Option<sys::events::SDL_Event> {
+        let mut memory = Vec::new();
+        let raw = self.to_ll_with(Some(&mut memory))?;
+        if !memory.is_empty() {
+            TEMPORARY_EVENT_MEMORY.with(|temporary| {
+                let mut temporary = temporary.borrow_mut();
+                if temporary.len() == MAX_TEMPORARY_EVENTS {
+                    temporary.pop_front();
+                }
+                temporary.push_back(memory);
+            });
+        }
+        Some(raw)
+    }
+
+    /// Returns `true` if [`to_ll`](Self::to_ll) would succeed, without copying any strings.
+    #[cfg(feature = "serde")]
+    pub(crate) fn has_raw_form(&self) -> bool {
+        self.to_ll_with(None).is_some()
+    }
+
+    /// Like [`to_ll`](Self::to_ll), but the strings are owned by `memory`. Without `memory`,
+    /// the string fields are null.
+    fn to_ll_with(&self, memory: Option<&mut Vec<EventMemory>>) -> Option<sys::events::SDL_Event> {
+        let mut ret = mem::MaybeUninit::zeroed();
         match *self {
             Event::User {
                 window_id,
@@ -1051,12 +1594,44 @@ impl Event {
                 }
             }
 
+            Event::LocaleChanged { timestamp } => {
+                let event = sys::events::SDL_CommonEvent {
+                    r#type: sys::events::SDL_EVENT_LOCALE_CHANGED.into(),
+                    timestamp,
+                    reserved: 0,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_CommonEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::SystemThemeChanged { timestamp } => {
+                let event = sys::events::SDL_CommonEvent {
+                    r#type: sys::events::SDL_EVENT_SYSTEM_THEME_CHANGED.into(),
+                    timestamp,
+                    reserved: 0,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_CommonEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
             Event::Window {
                 timestamp,
                 window_id,
                 win_event,
             } => {
-                let (win_event_id, data1, data2) = win_event.to_ll();
+                let (win_event_id, data1, data2) = win_event.to_ll()?;
                 let event = sys::events::SDL_WindowEvent {
                     r#type: win_event_id.into(),
                     timestamp,
@@ -1139,6 +1714,70 @@ impl Event {
                     Some(ret.assume_init())
                 }
             }
+            Event::KeyboardAdded { timestamp, which } => {
+                let event = sys::events::SDL_KeyboardDeviceEvent {
+                    r#type: sys::events::SDL_EVENT_KEYBOARD_ADDED,
+                    reserved: 0,
+                    timestamp,
+                    which,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_KeyboardDeviceEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+            Event::KeyboardRemoved { timestamp, which } => {
+                let event = sys::events::SDL_KeyboardDeviceEvent {
+                    r#type: sys::events::SDL_EVENT_KEYBOARD_REMOVED,
+                    reserved: 0,
+                    timestamp,
+                    which,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_KeyboardDeviceEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+            Event::MouseAdded { timestamp, which } => {
+                let event = sys::events::SDL_MouseDeviceEvent {
+                    r#type: sys::events::SDL_EVENT_MOUSE_ADDED,
+                    reserved: 0,
+                    timestamp,
+                    which,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_MouseDeviceEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+            Event::MouseRemoved { timestamp, which } => {
+                let event = sys::events::SDL_MouseDeviceEvent {
+                    r#type: sys::events::SDL_EVENT_MOUSE_REMOVED,
+                    reserved: 0,
+                    timestamp,
+                    which,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_MouseDeviceEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
             Event::MouseMotion {
                 timestamp,
                 window_id,
@@ -1403,17 +2042,40 @@ impl Event {
                     Some(ret.assume_init())
                 }
             }
-            Event::ControllerAxisMotion {
+            Event::JoyBatteryUpdated {
                 timestamp,
                 which,
-                axis,
-                value,
+                state,
+                percent,
             } => {
-                let event = SDL_GamepadAxisEvent {
-                    r#type: sys::events::SDL_EVENT_GAMEPAD_AXIS_MOTION,
+                let event = sys::events::SDL_JoyBatteryEvent {
+                    r#type: sys::events::SDL_EVENT_JOYSTICK_BATTERY_UPDATED,
+                    reserved: 0,
                     timestamp,
                     which,
-                    axis: axis.into(),
+                    state: state.to_ll(),
+                    percent,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_JoyBatteryEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+            Event::ControllerAxisMotion {
+                timestamp,
+                which,
+                axis,
+                value,
+            } => {
+                let event = SDL_GamepadAxisEvent {
+                    r#type: sys::events::SDL_EVENT_GAMEPAD_AXIS_MOTION,
+                    timestamp,
+                    which,
+                    axis: axis.into(),
                     value,
                     padding1: 0,
                     padding2: 0,
@@ -1430,147 +2092,1037 @@ impl Event {
                     Some(ret.assume_init())
                 }
             }
-            Event::ControllerButtonDown {
+            Event::ControllerButtonDown {
+                timestamp,
+                which,
+                button,
+            } => {
+                let event = SDL_GamepadButtonEvent {
+                    r#type: sys::events::SDL_EVENT_GAMEPAD_BUTTON_DOWN,
+                    timestamp,
+                    which,
+                    // This conversion turns an i32 into a u8; signed-to-unsigned conversions
+                    // are a bit of a code smell, but that appears to be how SDL defines it.
+                    button: button.into(),
+                    down: true,
+                    padding1: 0,
+                    padding2: 0,
+                    reserved: 0,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_GamepadButtonEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::ControllerButtonUp {
+                timestamp,
+                which,
+                button,
+            } => {
+                let event = SDL_GamepadButtonEvent {
+                    r#type: sys::events::SDL_EVENT_GAMEPAD_BUTTON_UP,
+                    reserved: 0,
+                    timestamp,
+                    which,
+                    button: button.into(),
+                    down: false,
+                    padding1: 0,
+                    padding2: 0,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_GamepadButtonEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::ControllerDeviceAdded { timestamp, which } => {
+                let event = SDL_GamepadDeviceEvent {
+                    r#type: sys::events::SDL_EVENT_GAMEPAD_ADDED,
+                    timestamp,
+                    which,
+                    reserved: 0,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_GamepadDeviceEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::ControllerDeviceRemoved { timestamp, which } => {
+                let event = SDL_GamepadDeviceEvent {
+                    r#type: sys::events::SDL_EVENT_GAMEPAD_REMOVED,
+                    timestamp,
+                    which,
+                    reserved: 0,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_GamepadDeviceEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::ControllerDeviceRemapped { timestamp, which } => {
+                let event = SDL_GamepadDeviceEvent {
+                    r#type: sys::events::SDL_EVENT_GAMEPAD_REMAPPED,
+                    timestamp,
+                    which,
+                    reserved: 0,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_GamepadDeviceEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::CameraDeviceAdded { timestamp, which } => {
+                let event = SDL_CameraDeviceEvent {
+                    r#type: sys::events::SDL_EVENT_CAMERA_DEVICE_ADDED,
+                    timestamp,
+                    which,
+                    reserved: 0,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_CameraDeviceEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::CameraDeviceRemoved { timestamp, which } => {
+                let event = SDL_CameraDeviceEvent {
+                    r#type: sys::events::SDL_EVENT_CAMERA_DEVICE_REMOVED,
+                    timestamp,
+                    which,
+                    reserved: 0,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_CameraDeviceEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::CameraDeviceApproved { timestamp, which } => {
+                let event = SDL_CameraDeviceEvent {
+                    r#type: sys::events::SDL_EVENT_CAMERA_DEVICE_APPROVED,
+                    timestamp,
+                    which,
+                    reserved: 0,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_CameraDeviceEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::CameraDeviceDenied { timestamp, which } => {
+                let event = SDL_CameraDeviceEvent {
+                    r#type: sys::events::SDL_EVENT_CAMERA_DEVICE_DENIED,
+                    timestamp,
+                    which,
+                    reserved: 0,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_CameraDeviceEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::PenProximityIn {
+                timestamp,
+                window_id,
+                which,
+            } => {
+                let event = SDL_PenProximityEvent {
+                    r#type: sys::events::SDL_EVENT_PEN_PROXIMITY_IN,
+                    reserved: 0,
+                    timestamp,
+                    windowID: window_id,
+                    which,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_PenProximityEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::PenProximityOut {
+                timestamp,
+                window_id,
+                which,
+            } => {
+                let event = SDL_PenProximityEvent {
+                    r#type: sys::events::SDL_EVENT_PEN_PROXIMITY_OUT,
+                    reserved: 0,
+                    timestamp,
+                    windowID: window_id,
+                    which,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_PenProximityEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::PenDown {
+                timestamp,
+                window_id,
+                which,
+                state,
+                x,
+                y,
+                eraser,
+            } => {
+                let event = SDL_PenTouchEvent {
+                    r#type: sys::events::SDL_EVENT_PEN_DOWN,
+                    reserved: 0,
+                    timestamp,
+                    windowID: window_id,
+                    which,
+                    pen_state: state.bits(),
+                    x,
+                    y,
+                    eraser,
+                    down: true,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_PenTouchEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::PenUp {
+                timestamp,
+                window_id,
+                which,
+                state,
+                x,
+                y,
+                eraser,
+            } => {
+                let event = SDL_PenTouchEvent {
+                    r#type: sys::events::SDL_EVENT_PEN_UP,
+                    reserved: 0,
+                    timestamp,
+                    windowID: window_id,
+                    which,
+                    pen_state: state.bits(),
+                    x,
+                    y,
+                    eraser,
+                    down: false,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_PenTouchEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::PenButtonDown {
+                timestamp,
+                window_id,
+                which,
+                state,
+                x,
+                y,
+                button,
+            } => {
+                let event = SDL_PenButtonEvent {
+                    r#type: sys::events::SDL_EVENT_PEN_BUTTON_DOWN,
+                    reserved: 0,
+                    timestamp,
+                    windowID: window_id,
+                    which,
+                    pen_state: state.bits(),
+                    x,
+                    y,
+                    button,
+                    down: true,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_PenButtonEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::PenButtonUp {
+                timestamp,
+                window_id,
+                which,
+                state,
+                x,
+                y,
+                button,
+            } => {
+                let event = SDL_PenButtonEvent {
+                    r#type: sys::events::SDL_EVENT_PEN_BUTTON_UP,
+                    reserved: 0,
+                    timestamp,
+                    windowID: window_id,
+                    which,
+                    pen_state: state.bits(),
+                    x,
+                    y,
+                    button,
+                    down: false,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_PenButtonEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::PenMotion {
+                timestamp,
+                window_id,
+                which,
+                state,
+                x,
+                y,
+            } => {
+                let event = SDL_PenMotionEvent {
+                    r#type: sys::events::SDL_EVENT_PEN_MOTION,
+                    reserved: 0,
+                    timestamp,
+                    windowID: window_id,
+                    which,
+                    pen_state: state.bits(),
+                    x,
+                    y,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_PenMotionEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::PenAxis {
+                timestamp,
+                window_id,
+                which,
+                state,
+                x,
+                y,
+                axis,
+                value,
+            } => {
+                let event = SDL_PenAxisEvent {
+                    r#type: sys::events::SDL_EVENT_PEN_AXIS,
+                    reserved: 0,
+                    timestamp,
+                    windowID: window_id,
+                    which,
+                    pen_state: state.bits(),
+                    x,
+                    y,
+                    axis: axis.to_ll(),
+                    value,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_PenAxisEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::Display {
+                timestamp,
+                display,
+                display_event,
+            } => {
+                let display_event = display_event.to_ll()?;
+                let event = SDL_DisplayEvent {
+                    r#type: SDL_EventType(display_event.0),
+                    displayID: display.id,
+                    data1: display_event.1,
+                    data2: 0,
+                    timestamp,
+                    reserved: 0,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_DisplayEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::AppTerminating { timestamp } => {
+                let event = sys::events::SDL_CommonEvent {
+                    r#type: sys::events::SDL_EVENT_TERMINATING.into(),
+                    reserved: 0,
+                    timestamp,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_CommonEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::AppLowMemory { timestamp } => {
+                let event = sys::events::SDL_CommonEvent {
+                    r#type: sys::events::SDL_EVENT_LOW_MEMORY.into(),
+                    reserved: 0,
+                    timestamp,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_CommonEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::AppWillEnterBackground { timestamp } => {
+                let event = sys::events::SDL_CommonEvent {
+                    r#type: sys::events::SDL_EVENT_WILL_ENTER_BACKGROUND.into(),
+                    reserved: 0,
+                    timestamp,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_CommonEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::AppDidEnterBackground { timestamp } => {
+                let event = sys::events::SDL_CommonEvent {
+                    r#type: sys::events::SDL_EVENT_DID_ENTER_BACKGROUND.into(),
+                    reserved: 0,
+                    timestamp,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_CommonEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::AppWillEnterForeground { timestamp } => {
+                let event = sys::events::SDL_CommonEvent {
+                    r#type: sys::events::SDL_EVENT_WILL_ENTER_FOREGROUND.into(),
+                    reserved: 0,
+                    timestamp,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_CommonEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::AppDidEnterForeground { timestamp } => {
+                let event = sys::events::SDL_CommonEvent {
+                    r#type: sys::events::SDL_EVENT_DID_ENTER_FOREGROUND.into(),
+                    reserved: 0,
+                    timestamp,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_CommonEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::ClipboardUpdate { timestamp } => {
+                let event = sys::events::SDL_ClipboardEvent {
+                    r#type: sys::events::SDL_EVENT_CLIPBOARD_UPDATE,
+                    reserved: 0,
+                    timestamp,
+                    owner: false,
+                    num_mime_types: 0,
+                    mime_types: ptr::null_mut(),
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_ClipboardEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::RenderTargetsReset { timestamp } => {
+                let event = sys::events::SDL_RenderEvent {
+                    r#type: sys::events::SDL_EVENT_RENDER_TARGETS_RESET,
+                    reserved: 0,
+                    timestamp,
+                    windowID: 0,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_RenderEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::RenderDeviceReset { timestamp } => {
+                let event = sys::events::SDL_RenderEvent {
+                    r#type: sys::events::SDL_EVENT_RENDER_DEVICE_RESET,
+                    reserved: 0,
+                    timestamp,
+                    windowID: 0,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_RenderEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::TextEditing {
+                timestamp,
+                window_id,
+                ref text,
+                start,
+                length,
+            } => {
+                let event = sys::events::SDL_TextEditingEvent {
+                    r#type: sys::events::SDL_EVENT_TEXT_EDITING,
+                    reserved: 0,
+                    timestamp,
+                    windowID: window_id,
+                    text: event_string(memory, text)?,
+                    start,
+                    length,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_TextEditingEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::TextEditingCandidates {
+                timestamp,
+                window_id,
+                ref candidates,
+                selected_candidate,
+                horizontal,
+            } => {
+                let event = sys::events::SDL_TextEditingCandidatesEvent {
+                    r#type: sys::events::SDL_EVENT_TEXT_EDITING_CANDIDATES,
+                    reserved: 0,
+                    timestamp,
+                    windowID: window_id,
+                    candidates: event_string_list(memory, candidates)?,
+                    num_candidates: candidates.len() as i32,
+                    selected_candidate: selected_candidate.map_or(-1, |i| i as i32),
+                    horizontal,
+                    padding1: 0,
+                    padding2: 0,
+                    padding3: 0,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_TextEditingCandidatesEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::TextInput {
+                timestamp,
+                window_id,
+                ref text,
+            } => {
+                let event = sys::events::SDL_TextInputEvent {
+                    r#type: sys::events::SDL_EVENT_TEXT_INPUT,
+                    reserved: 0,
+                    timestamp,
+                    windowID: window_id,
+                    text: event_string(memory, text)?,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_TextInputEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::ControllerTouchpadDown {
+                timestamp,
+                which,
+                touchpad,
+                finger,
+                x,
+                y,
+                pressure,
+            } => {
+                let event = sys::events::SDL_GamepadTouchpadEvent {
+                    r#type: sys::events::SDL_EVENT_GAMEPAD_TOUCHPAD_DOWN,
+                    reserved: 0,
+                    timestamp,
+                    which,
+                    touchpad,
+                    finger,
+                    x,
+                    y,
+                    pressure,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_GamepadTouchpadEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::ControllerTouchpadMotion {
+                timestamp,
+                which,
+                touchpad,
+                finger,
+                x,
+                y,
+                pressure,
+            } => {
+                let event = sys::events::SDL_GamepadTouchpadEvent {
+                    r#type: sys::events::SDL_EVENT_GAMEPAD_TOUCHPAD_MOTION,
+                    reserved: 0,
+                    timestamp,
+                    which,
+                    touchpad,
+                    finger,
+                    x,
+                    y,
+                    pressure,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_GamepadTouchpadEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::ControllerTouchpadUp {
+                timestamp,
+                which,
+                touchpad,
+                finger,
+                x,
+                y,
+                pressure,
+            } => {
+                let event = sys::events::SDL_GamepadTouchpadEvent {
+                    r#type: sys::events::SDL_EVENT_GAMEPAD_TOUCHPAD_UP,
+                    reserved: 0,
+                    timestamp,
+                    which,
+                    touchpad,
+                    finger,
+                    x,
+                    y,
+                    pressure,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_GamepadTouchpadEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            #[cfg(feature = "hidapi")]
+            Event::ControllerSensorUpdated {
+                timestamp,
+                which,
+                sensor,
+                data,
+            } => {
+                let event = sys::events::SDL_GamepadSensorEvent {
+                    r#type: sys::events::SDL_EVENT_GAMEPAD_SENSOR_UPDATE,
+                    reserved: 0,
+                    timestamp,
+                    which,
+                    sensor: Into::<sys::sensor::SDL_SensorType>::into(sensor).0,
+                    data,
+                    sensor_timestamp: 0,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_GamepadSensorEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::FingerDown {
+                timestamp,
+                touch_id,
+                finger_id,
+                x,
+                y,
+                dx,
+                dy,
+                pressure,
+            } => {
+                let event = sys::events::SDL_TouchFingerEvent {
+                    r#type: sys::events::SDL_EVENT_FINGER_DOWN,
+                    reserved: 0,
+                    timestamp,
+                    touchID: touch_id,
+                    fingerID: finger_id,
+                    x,
+                    y,
+                    dx,
+                    dy,
+                    pressure,
+                    windowID: 0,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_TouchFingerEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::FingerUp {
+                timestamp,
+                touch_id,
+                finger_id,
+                x,
+                y,
+                dx,
+                dy,
+                pressure,
+            } => {
+                let event = sys::events::SDL_TouchFingerEvent {
+                    r#type: sys::events::SDL_EVENT_FINGER_UP,
+                    reserved: 0,
+                    timestamp,
+                    touchID: touch_id,
+                    fingerID: finger_id,
+                    x,
+                    y,
+                    dx,
+                    dy,
+                    pressure,
+                    windowID: 0,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_TouchFingerEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::FingerMotion {
+                timestamp,
+                touch_id,
+                finger_id,
+                x,
+                y,
+                dx,
+                dy,
+                pressure,
+            } => {
+                let event = sys::events::SDL_TouchFingerEvent {
+                    r#type: sys::events::SDL_EVENT_FINGER_MOTION,
+                    reserved: 0,
+                    timestamp,
+                    touchID: touch_id,
+                    fingerID: finger_id,
+                    x,
+                    y,
+                    dx,
+                    dy,
+                    pressure,
+                    windowID: 0,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_TouchFingerEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::DropFile {
+                timestamp,
+                window_id,
+                ref filename,
+            } => {
+                let event = sys::events::SDL_DropEvent {
+                    r#type: sys::events::SDL_EVENT_DROP_FILE,
+                    reserved: 0,
+                    timestamp,
+                    windowID: window_id,
+                    x: 0.0,
+                    y: 0.0,
+                    source: ptr::null(),
+                    data: event_string(memory, filename)?,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_DropEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
+
+            Event::DropText {
                 timestamp,
-                which,
-                button,
+                window_id,
+                ref filename,
             } => {
-                let event = SDL_GamepadButtonEvent {
-                    r#type: sys::events::SDL_EVENT_GAMEPAD_BUTTON_DOWN,
-                    timestamp,
-                    which,
-                    // This conversion turns an i32 into a u8; signed-to-unsigned conversions
-                    // are a bit of a code smell, but that appears to be how SDL defines it.
-                    button: button.into(),
-                    down: true,
-                    padding1: 0,
-                    padding2: 0,
+                let event = sys::events::SDL_DropEvent {
+                    r#type: sys::events::SDL_EVENT_DROP_TEXT,
                     reserved: 0,
+                    timestamp,
+                    windowID: window_id,
+                    x: 0.0,
+                    y: 0.0,
+                    source: ptr::null(),
+                    data: event_string(memory, filename)?,
                 };
                 unsafe {
                     ptr::copy(
                         &event,
-                        ret.as_mut_ptr() as *mut sys::events::SDL_GamepadButtonEvent,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_DropEvent,
                         1,
                     );
                     Some(ret.assume_init())
                 }
             }
 
-            Event::ControllerButtonUp {
+            Event::DropBegin {
                 timestamp,
-                which,
-                button,
+                window_id,
             } => {
-                let event = SDL_GamepadButtonEvent {
-                    r#type: sys::events::SDL_EVENT_GAMEPAD_BUTTON_UP,
+                let event = sys::events::SDL_DropEvent {
+                    r#type: sys::events::SDL_EVENT_DROP_BEGIN,
                     reserved: 0,
                     timestamp,
-                    which,
-                    button: button.into(),
-                    down: false,
-                    padding1: 0,
-                    padding2: 0,
+                    windowID: window_id,
+                    x: 0.0,
+                    y: 0.0,
+                    source: ptr::null(),
+                    data: ptr::null(),
                 };
                 unsafe {
                     ptr::copy(
                         &event,
-                        ret.as_mut_ptr() as *mut sys::events::SDL_GamepadButtonEvent,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_DropEvent,
                         1,
                     );
                     Some(ret.assume_init())
                 }
             }
 
-            Event::ControllerDeviceAdded { timestamp, which } => {
-                let event = SDL_GamepadDeviceEvent {
-                    r#type: sys::events::SDL_EVENT_GAMEPAD_ADDED,
-                    timestamp,
-                    which,
+            Event::DropComplete {
+                timestamp,
+                window_id,
+            } => {
+                let event = sys::events::SDL_DropEvent {
+                    r#type: sys::events::SDL_EVENT_DROP_COMPLETE,
                     reserved: 0,
+                    timestamp,
+                    windowID: window_id,
+                    x: 0.0,
+                    y: 0.0,
+                    source: ptr::null(),
+                    data: ptr::null(),
                 };
                 unsafe {
                     ptr::copy(
                         &event,
-                        ret.as_mut_ptr() as *mut sys::events::SDL_GamepadDeviceEvent,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_DropEvent,
                         1,
                     );
                     Some(ret.assume_init())
                 }
             }
 
-            Event::ControllerDeviceRemoved { timestamp, which } => {
-                let event = SDL_GamepadDeviceEvent {
-                    r#type: sys::events::SDL_EVENT_GAMEPAD_REMOVED,
+            Event::AudioDeviceAdded {
+                timestamp,
+                which,
+                iscapture,
+            } => {
+                let event = sys::events::SDL_AudioDeviceEvent {
+                    r#type: sys::events::SDL_EVENT_AUDIO_DEVICE_ADDED,
+                    reserved: 0,
                     timestamp,
                     which,
-                    reserved: 0,
+                    recording: iscapture,
+                    padding1: 0,
+                    padding2: 0,
+                    padding3: 0,
                 };
                 unsafe {
                     ptr::copy(
                         &event,
-                        ret.as_mut_ptr() as *mut sys::events::SDL_GamepadDeviceEvent,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_AudioDeviceEvent,
                         1,
                     );
                     Some(ret.assume_init())
                 }
             }
 
-            Event::ControllerDeviceRemapped { timestamp, which } => {
-                let event = SDL_GamepadDeviceEvent {
-                    r#type: sys::events::SDL_EVENT_GAMEPAD_REMAPPED,
+            Event::AudioDeviceRemoved {
+                timestamp,
+                which,
+                iscapture,
+            } => {
+                let event = sys::events::SDL_AudioDeviceEvent {
+                    r#type: sys::events::SDL_EVENT_AUDIO_DEVICE_REMOVED,
+                    reserved: 0,
                     timestamp,
                     which,
-                    reserved: 0,
+                    recording: iscapture,
+                    padding1: 0,
+                    padding2: 0,
+                    padding3: 0,
                 };
                 unsafe {
                     ptr::copy(
                         &event,
-                        ret.as_mut_ptr() as *mut sys::events::SDL_GamepadDeviceEvent,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_AudioDeviceEvent,
                         1,
                     );
                     Some(ret.assume_init())
                 }
             }
 
-            Event::Display {
-                timestamp,
-                display,
-                display_event,
-            } => {
-                let display_event = display_event.to_ll();
-                let event = SDL_DisplayEvent {
-                    r#type: SDL_EventType(display_event.0),
-                    displayID: display.id,
-                    data1: display_event.1,
-                    data2: 0,
-                    timestamp,
+            Event::Unknown { timestamp, type_ } => {
+                let event = sys::events::SDL_CommonEvent {
+                    r#type: type_,
                     reserved: 0,
+                    timestamp,
                 };
                 unsafe {
                     ptr::copy(
                         &event,
-                        ret.as_mut_ptr() as *mut sys::events::SDL_DisplayEvent,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_CommonEvent,
                         1,
                     );
                     Some(ret.assume_init())
                 }
             }
 
-            Event::FingerDown { .. }
-            | Event::FingerUp { .. }
-            | Event::FingerMotion { .. }
-            | Event::DollarRecord { .. }
-            | Event::MultiGesture { .. }
-            | Event::ClipboardUpdate { .. }
-            | Event::DropFile { .. }
-            | Event::TextEditing { .. }
-            | Event::TextInput { .. }
-            | Event::Unknown { .. }
-            | _ => {
-                // don't know how to convert!
-                None
-            }
+            // SDL3 doesn't have gesture events anymore.
+            Event::DollarRecord { .. } | Event::MultiGesture { .. } => None,
         }
     }
 
@@ -1652,6 +3204,12 @@ impl Event {
                         timestamp: event.timestamp,
                     }
                 }
+                EventType::LocaleChanged => Event::LocaleChanged {
+                    timestamp: raw.common.timestamp,
+                },
+                EventType::SystemThemeChanged => Event::SystemThemeChanged {
+                    timestamp: raw.common.timestamp,
+                },
 
                 EventType::DisplayOrientation
                 | EventType::DisplayAdded
@@ -1716,6 +3274,28 @@ impl Event {
                         length: event.length,
                     }
                 }
+                EventType::TextEditingCandidates => {
+                    let event = raw.edit_candidates;
+
+                    let candidates = if event.candidates.is_null() {
+                        Vec::new()
+                    } else {
+                        slice::from_raw_parts(event.candidates, event.num_candidates as usize)
+                            .iter()
+                            .map(|&candidate| {
+                                CStr::from_ptr(candidate).to_string_lossy().into_owned()
+                            })
+                            .collect()
+                    };
+
+                    Event::TextEditingCandidates {
+                        timestamp: event.timestamp,
+                        window_id: event.windowID,
+                        candidates,
+                        selected_candidate: usize::try_from(event.selected_candidate).ok(),
+                        horizontal: event.horizontal,
+                    }
+                }
                 EventType::TextInput => {
                     let event = raw.text;
 
@@ -1733,6 +3313,21 @@ impl Event {
                     }
                 }
 
+                EventType::KeyboardAdded => {
+                    let event = raw.kdevice;
+                    Event::KeyboardAdded {
+                        timestamp: event.timestamp,
+                        which: event.which,
+                    }
+                }
+                EventType::KeyboardRemoved => {
+                    let event = raw.kdevice;
+                    Event::KeyboardRemoved {
+                        timestamp: event.timestamp,
+                        which: event.which,
+                    }
+                }
+
                 EventType::MouseMotion => {
                     let event = raw.motion;
 
@@ -1788,6 +3383,21 @@ impl Event {
                     }
                 }
 
+                EventType::MouseAdded => {
+                    let event = raw.mdevice;
+                    Event::MouseAdded {
+                        timestamp: event.timestamp,
+                        which: event.which,
+                    }
+                }
+                EventType::MouseRemoved => {
+                    let event = raw.mdevice;
+                    Event::MouseRemoved {
+                        timestamp: event.timestamp,
+                        which: event.which,
+                    }
+                }
+
                 EventType::JoyAxisMotion => {
                     let event = raw.jaxis;
                     Event::JoyAxisMotion {
@@ -1836,6 +3446,15 @@ impl Event {
                         which: event.which,
                     }
                 }
+                EventType::JoyBatteryUpdated => {
+                    let event = raw.jbattery;
+                    Event::JoyBatteryUpdated {
+                        timestamp: event.timestamp,
+                        which: event.which,
+                        state: PowerLevel::from_ll(event.state),
+                        percent: event.percent,
+                    }
+                }
 
                 EventType::ControllerAxisMotion => {
                     let event = raw.gaxis;
@@ -2041,6 +3660,124 @@ impl Event {
                     }
                 }
 
+                EventType::CameraDeviceAdded => {
+                    let event = raw.cdevice;
+                    Event::CameraDeviceAdded {
+                        timestamp: event.timestamp,
+                        which: event.which,
+                    }
+                }
+                EventType::CameraDeviceRemoved => {
+                    let event = raw.cdevice;
+                    Event::CameraDeviceRemoved {
+                        timestamp: event.timestamp,
+                        which: event.which,
+                    }
+                }
+                EventType::CameraDeviceApproved => {
+                    let event = raw.cdevice;
+                    Event::CameraDeviceApproved {
+                        timestamp: event.timestamp,
+                        which: event.which,
+                    }
+                }
+                EventType::CameraDeviceDenied => {
+                    let event = raw.cdevice;
+                    Event::CameraDeviceDenied {
+                        timestamp: event.timestamp,
+                        which: event.which,
+                    }
+                }
+
+                EventType::PenProximityIn => {
+                    let event = raw.pproximity;
+                    Event::PenProximityIn {
+                        timestamp: event.timestamp,
+                        window_id: event.windowID,
+                        which: event.which,
+                    }
+                }
+                EventType::PenProximityOut => {
+                    let event = raw.pproximity;
+                    Event::PenProximityOut {
+                        timestamp: event.timestamp,
+                        window_id: event.windowID,
+                        which: event.which,
+                    }
+                }
+                EventType::PenDown => {
+                    let event = raw.ptouch;
+                    Event::PenDown {
+                        timestamp: event.timestamp,
+                        window_id: event.windowID,
+                        which: event.which,
+                        state: PenInputFlags::from_bits_retain(event.pen_state),
+                        x: event.x,
+                        y: event.y,
+                        eraser: event.eraser,
+                    }
+                }
+                EventType::PenUp => {
+                    let event = raw.ptouch;
+                    Event::PenUp {
+                        timestamp: event.timestamp,
+                        window_id: event.windowID,
+                        which: event.which,
+                        state: PenInputFlags::from_bits_retain(event.pen_state),
+                        x: event.x,
+                        y: event.y,
+                        eraser: event.eraser,
+                    }
+                }
+                EventType::PenButtonDown => {
+                    let event = raw.pbutton;
+                    Event::PenButtonDown {
+                        timestamp: event.timestamp,
+                        window_id: event.windowID,
+                        which: event.which,
+                        state: PenInputFlags::from_bits_retain(event.pen_state),
+                        x: event.x,
+                        y: event.y,
+                        button: event.button,
+                    }
+                }
+                EventType::PenButtonUp => {
+                    let event = raw.pbutton;
+                    Event::PenButtonUp {
+                        timestamp: event.timestamp,
+                        window_id: event.windowID,
+                        which: event.which,
+                        state: PenInputFlags::from_bits_retain(event.pen_state),
+                        x: event.x,
+                        y: event.y,
+                        button: event.button,
+                    }
+                }
+                EventType::PenMotion => {
+                    let event = raw.pmotion;
+                    Event::PenMotion {
+                        timestamp: event.timestamp,
+                        window_id: event.windowID,
+                        which: event.which,
+                        state: PenInputFlags::from_bits_retain(event.pen_state),
+                        x: event.x,
+                        y: event.y,
+                    }
+                }
+                EventType::PenAxis => {
+                    let event = raw.paxis;
+                    Event::PenAxis {
+                        timestamp: event.timestamp,
+                        window_id: event.windowID,
+                        which: event.which,
+                        state: PenInputFlags::from_bits_retain(event.pen_state),
+                        x: event.x,
+                        y: event.y,
+                        axis: PenAxis::from_ll(event.axis),
+                        value: event.value,
+                    }
+                }
+
                 EventType::RenderTargetsReset => Event::RenderTargetsReset {
                     timestamp: raw.common.timestamp,
                 },
@@ -2141,22 +3878,30 @@ impl Event {
             | (Self::AppDidEnterBackground { .. }, Self::AppDidEnterBackground { .. })
             | (Self::AppWillEnterForeground { .. }, Self::AppWillEnterForeground { .. })
             | (Self::AppDidEnterForeground { .. }, Self::AppDidEnterForeground { .. })
+            | (Self::LocaleChanged { .. }, Self::LocaleChanged { .. })
+            | (Self::SystemThemeChanged { .. }, Self::SystemThemeChanged { .. })
             | (Self::Display { .. }, Self::Display { .. })
             | (Self::Window { .. }, Self::Window { .. })
             | (Self::KeyDown { .. }, Self::KeyDown { .. })
             | (Self::KeyUp { .. }, Self::KeyUp { .. })
             | (Self::TextEditing { .. }, Self::TextEditing { .. })
+            | (Self::TextEditingCandidates { .. }, Self::TextEditingCandidates { .. })
             | (Self::TextInput { .. }, Self::TextInput { .. })
+            | (Self::KeyboardAdded { .. }, Self::KeyboardAdded { .. })
+            | (Self::KeyboardRemoved { .. }, Self::KeyboardRemoved { .. })
             | (Self::MouseMotion { .. }, Self::MouseMotion { .. })
             | (Self::MouseButtonDown { .. }, Self::MouseButtonDown { .. })
             | (Self::MouseButtonUp { .. }, Self::MouseButtonUp { .. })
             | (Self::MouseWheel { .. }, Self::MouseWheel { .. })
+            | (Self::MouseAdded { .. }, Self::MouseAdded { .. })
+            | (Self::MouseRemoved { .. }, Self::MouseRemoved { .. })
             | (Self::JoyAxisMotion { .. }, Self::JoyAxisMotion { .. })
             | (Self::JoyHatMotion { .. }, Self::JoyHatMotion { .. })
             | (Self::JoyButtonDown { .. }, Self::JoyButtonDown { .. })
             | (Self::JoyButtonUp { .. }, Self::JoyButtonUp { .. })
             | (Self::JoyDeviceAdded { .. }, Self::JoyDeviceAdded { .. })
             | (Self::JoyDeviceRemoved { .. }, Self::JoyDeviceRemoved { .. })
+            | (Self::JoyBatteryUpdated { .. }, Self::JoyBatteryUpdated { .. })
             | (Self::ControllerAxisMotion { .. }, Self::ControllerAxisMotion { .. })
             | (Self::ControllerButtonDown { .. }, Self::ControllerButtonDown { .. })
             | (Self::ControllerButtonUp { .. }, Self::ControllerButtonUp { .. })
@@ -2175,6 +3920,18 @@ impl Event {
             | (Self::DropComplete { .. }, Self::DropComplete { .. })
             | (Self::AudioDeviceAdded { .. }, Self::AudioDeviceAdded { .. })
             | (Self::AudioDeviceRemoved { .. }, Self::AudioDeviceRemoved { .. })
+            | (Self::CameraDeviceAdded { .. }, Self::CameraDeviceAdded { .. })
+            | (Self::CameraDeviceRemoved { .. }, Self::CameraDeviceRemoved { .. })
+            | (Self::CameraDeviceApproved { .. }, Self::CameraDeviceApproved { .. })
+            | (Self::CameraDeviceDenied { .. }, Self::CameraDeviceDenied { .. })
+            | (Self::PenProximityIn { .. }, Self::PenProximityIn { .. })
+            | (Self::PenProximityOut { .. }, Self::PenProximityOut { .. })
+            | (Self::PenDown { .. }, Self::PenDown { .. })
+            | (Self::PenUp { .. }, Self::PenUp { .. })
+            | (Self::PenButtonDown { .. }, Self::PenButtonDown { .. })
+            | (Self::PenButtonUp { .. }, Self::PenButtonUp { .. })
+            | (Self::PenMotion { .. }, Self::PenMotion { .. })
+            | (Self::PenAxis { .. }, Self::PenAxis { .. })
             | (Self::RenderTargetsReset { .. }, Self::RenderTargetsReset { .. })
             | (Self::RenderDeviceReset { .. }, Self::RenderDeviceReset { .. })
             | (Self::User { .. }, Self::User { .. })
@@ -2209,21 +3966,29 @@ impl Event {
             Self::AppDidEnterBackground { timestamp, .. } => timestamp,
             Self::AppWillEnterForeground { timestamp, .. } => timestamp,
             Self::AppDidEnterForeground { timestamp, .. } => timestamp,
+            Self::LocaleChanged { timestamp, .. } => timestamp,
+            Self::SystemThemeChanged { timestamp, .. } => timestamp,
             Self::Display { timestamp, .. } => timestamp,
             Self::KeyDown { timestamp, .. } => timestamp,
             Self::KeyUp { timestamp, .. } => timestamp,
             Self::TextEditing { timestamp, .. } => timestamp,
+            Self::TextEditingCandidates { timestamp, .. } => timestamp,
             Self::TextInput { timestamp, .. } => timestamp,
+            Self::KeyboardAdded { timestamp, .. } => timestamp,
+            Self::KeyboardRemoved { timestamp, .. } => timestamp,
             Self::MouseMotion { timestamp, .. } => timestamp,
             Self::MouseButtonDown { timestamp, .. } => timestamp,
             Self::MouseButtonUp { timestamp, .. } => timestamp,
             Self::MouseWheel { timestamp, .. } => timestamp,
+            Self::MouseAdded { timestamp, .. } => timestamp,
+            Self::MouseRemoved { timestamp, .. } => timestamp,
             Self::JoyAxisMotion { timestamp, .. } => timestamp,
             Self::JoyHatMotion { timestamp, .. } => timestamp,
             Self::JoyButtonDown { timestamp, .. } => timestamp,
             Self::JoyButtonUp { timestamp, .. } => timestamp,
             Self::JoyDeviceAdded { timestamp, .. } => timestamp,
             Self::JoyDeviceRemoved { timestamp, .. } => timestamp,
+            Self::JoyBatteryUpdated { timestamp, .. } => timestamp,
             Self::ControllerAxisMotion { timestamp, .. } => timestamp,
             Self::ControllerButtonDown { timestamp, .. } => timestamp,
             Self::ControllerButtonUp { timestamp, .. } => timestamp,
@@ -2247,6 +4012,18 @@ impl Event {
             Self::DropComplete { timestamp, .. } => timestamp,
             Self::AudioDeviceAdded { timestamp, .. } => timestamp,
             Self::AudioDeviceRemoved { timestamp, .. } => timestamp,
+            Self::CameraDeviceAdded { timestamp, .. } => timestamp,
+            Self::CameraDeviceRemoved { timestamp, .. } => timestamp,
+            Self::CameraDeviceApproved { timestamp, .. } => timestamp,
+            Self::CameraDeviceDenied { timestamp, .. } => timestamp,
+            Self::PenProximityIn { timestamp, .. } => timestamp,
+            Self::PenProximityOut { timestamp, .. } => timestamp,
+            Self::PenDown { timestamp, .. } => timestamp,
+            Self::PenUp { timestamp, .. } => timestamp,
+            Self::PenButtonDown { timestamp, .. } => timestamp,
+            Self::PenButtonUp { timestamp, .. } => timestamp,
+            Self::PenMotion { timestamp, .. } => timestamp,
+            Self::PenAxis { timestamp, .. } => timestamp,
             Self::RenderTargetsReset { timestamp, .. } => timestamp,
             Self::RenderDeviceReset { timestamp, .. } => timestamp,
             Self::User { timestamp, .. } => timestamp,
@@ -2280,6 +4057,7 @@ impl Event {
             Self::KeyDown { window_id, .. } => Some(*window_id),
             Self::KeyUp { window_id, .. } => Some(*window_id),
             Self::TextEditing { window_id, .. } => Some(*window_id),
+            Self::TextEditingCandidates { window_id, .. } => Some(*window_id),
             Self::TextInput { window_id, .. } => Some(*window_id),
             Self::MouseMotion { window_id, .. } => Some(*window_id),
             Self::MouseButtonDown { window_id, .. } => Some(*window_id),
@@ -2289,6 +4067,14 @@ impl Event {
             Self::DropText { window_id, .. } => Some(*window_id),
             Self::DropBegin { window_id, .. } => Some(*window_id),
             Self::DropComplete { window_id, .. } => Some(*window_id),
+            Self::PenProximityIn { window_id, .. } => Some(*window_id),
+            Self::PenProximityOut { window_id, .. } => Some(*window_id),
+            Self::PenDown { window_id, .. } => Some(*window_id),
+            Self::PenUp { window_id, .. } => Some(*window_id),
+            Self::PenButtonDown { window_id, .. } => Some(*window_id),
+            Self::PenButtonUp { window_id, .. } => Some(*window_id),
+            Self::PenMotion { window_id, .. } => Some(*window_id),
+            Self::PenAxis { window_id, .. } => Some(*window_id),
             Self::User { window_id, .. } => Some(*window_id),
             _ => None,
         }
@@ -2358,7 +4144,13 @@ impl Event {
     /// assert!(another_ev.is_keyboard() == false); // Not a keyboard event!
     /// ```
     pub fn is_keyboard(&self) -> bool {
-        matches!(self, Self::KeyDown { .. } | Self::KeyUp { .. })
+        matches!(
+            self,
+            Self::KeyDown { .. }
+                | Self::KeyUp { .. }
+                | Self::KeyboardAdded { .. }
+                | Self::KeyboardRemoved { .. }
+        )
     }
 
     /// Returns `true` if this is a text event.
@@ -2381,7 +4173,10 @@ impl Event {
     /// assert!(another_ev.is_text() == false); // Not a text event!
     /// ```
     pub fn is_text(&self) -> bool {
-        matches!(self, Self::TextEditing { .. } | Self::TextInput { .. })
+        matches!(
+            self,
+            Self::TextEditing { .. } | Self::TextEditingCandidates { .. } | Self::TextInput { .. }
+        )
     }
 
     /// Returns `true` if this is a mouse event.
@@ -2416,6 +4211,8 @@ impl Event {
                 | Self::MouseButtonDown { .. }
                 | Self::MouseButtonUp { .. }
                 | Self::MouseWheel { .. }
+                | Self::MouseAdded { .. }
+                | Self::MouseRemoved { .. }
         )
     }
 
@@ -2477,6 +4274,7 @@ impl Event {
                 | Self::JoyButtonUp { .. }
                 | Self::JoyDeviceAdded { .. }
                 | Self::JoyDeviceRemoved { .. }
+                | Self::JoyBatteryUpdated { .. }
         )
     }
 
@@ -2565,6 +4363,117 @@ impl Event {
         )
     }
 
+    /// Returns `true` if this is a camera event.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sdl3::event::Event;
+    ///
+    /// let ev = Event::CameraDeviceApproved {
+    ///     timestamp: 0,
+    ///     which: 3,
+    /// };
+    /// assert!(ev.is_camera());
+    ///
+    /// let another_ev = Event::Quit {
+    ///     timestamp: 0,
+    /// };
+    /// assert!(another_ev.is_camera() == false); // Not a camera event!
+    /// ```
+    pub fn is_camera(&self) -> bool {
+        matches!(
+            self,
+            Self::CameraDeviceAdded { .. }
+                | Self::CameraDeviceRemoved { .. }
+                | Self::CameraDeviceApproved { .. }
+                | Self::CameraDeviceDenied { .. }
+        )
+    }
+
+    /// Returns `true` if this is a pen event.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sdl3::event::Event;
+    /// use sdl3::pen::PenInputFlags;
+    ///
+    /// let ev = Event::PenDown {
+    ///     timestamp: 0,
+    ///     window_id: 1,
+    ///     which: 2,
+    ///     state: PenInputFlags::DOWN,
+    ///     x: 10.0,
+    ///     y: 20.0,
+    ///     eraser: false,
+    /// };
+    /// assert!(ev.is_pen());
+    ///
+    /// let another_ev = Event::Quit {
+    ///     timestamp: 0,
+    /// };
+    /// assert!(another_ev.is_pen() == false); // Not a pen event!
+    /// ```
+    pub fn is_pen(&self) -> bool {
+        matches!(
+            self,
+            Self::PenProximityIn { .. }
+                | Self::PenProximityOut { .. }
+                | Self::PenDown { .. }
+                | Self::PenUp { .. }
+                | Self::PenButtonDown { .. }
+                | Self::PenButtonUp { .. }
+                | Self::PenMotion { .. }
+                | Self::PenAxis { .. }
+        )
+    }
+
+    /// Returns `true` if this event came from a pen: either a pen event, or a mouse or touch
+    /// event that SDL synthesized from pen input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sdl3::event::Event;
+    /// use sdl3::mouse::MouseButton;
+    /// use sdl3::pen::PEN_MOUSE_ID;
+    ///
+    /// let ev = Event::MouseButtonDown {
+    ///     timestamp: 0,
+    ///     window_id: 1,
+    ///     which: PEN_MOUSE_ID,
+    ///     mouse_btn: MouseButton::Left,
+    ///     clicks: 1,
+    ///     x: 10.0,
+    ///     y: 20.0,
+    /// };
+    /// assert!(ev.is_from_pen());
+    ///
+    /// let another_ev = Event::MouseButtonDown {
+    ///     timestamp: 0,
+    ///     window_id: 1,
+    ///     which: 0,
+    ///     mouse_btn: MouseButton::Left,
+    ///     clicks: 1,
+    ///     x: 10.0,
+    ///     y: 20.0,
+    /// };
+    /// assert!(another_ev.is_from_pen() == false); // A real mouse!
+    /// ```
+    pub fn is_from_pen(&self) -> bool {
+        match self {
+            Self::MouseMotion { which, .. }
+            | Self::MouseButtonDown { which, .. }
+            | Self::MouseButtonUp { which, .. }
+            | Self::MouseWheel { which, .. } => *which == pen::PEN_MOUSE_ID,
+            Self::FingerDown { touch_id, .. }
+            | Self::FingerUp { touch_id, .. }
+            | Self::FingerMotion { touch_id, .. } => *touch_id == pen::PEN_TOUCH_ID,
+            _ => self.is_pen(),
+        }
+    }
+
     /// Returns `true` if this is a render event.
     ///
     /// # Example
@@ -2666,12 +4575,20 @@ impl Event {
     }
 }
 
+/// Converts a polled event and frees the memory of its strings.
+unsafe fn polled_event(raw: mem::MaybeUninit<sys::events::SDL_Event>) -> Event {
+    let raw = raw.assume_init();
+    let event = Event::from_ll(raw);
+    free_event_memory(&raw);
+    event
+}
+
 unsafe fn poll_event() -> Option<Event> {
     let mut raw = mem::MaybeUninit::uninit();
     let has_pending = sys::events::SDL_PollEvent(raw.as_mut_ptr());
 
     if has_pending {
-        Some(Event::from_ll(raw.assume_init()))
+        Some(polled_event(raw))
     } else {
         None
     }
@@ -2682,7 +4599,7 @@ unsafe fn wait_event() -> Event {
     let success = sys::events::SDL_WaitEvent(raw.as_mut_ptr());
 
     if success {
-        Event::from_ll(raw.assume_init())
+        polled_event(raw)
     } else {
         panic!("{}", get_error())
     }
@@ -2693,7 +4610,7 @@ unsafe fn wait_event_timeout(timeout: u32) -> Option<Event> {
     let success = sys::events::SDL_WaitEventTimeout(raw.as_mut_ptr(), timeout as c_int);
 
     if success {
-        Some(Event::from_ll(raw.assume_init()))
+        Some(polled_event(raw))
     } else {
         None
     }
@@ -2767,6 +4684,37 @@ impl crate::EventPump {
         }
     }
 
+    /// Returns an asynchronous stream of events.
+    ///
+    /// The stream is woken whenever an event is added to the queue. Input from the OS only
+    /// reaches the queue when events are pumped on the main thread though, so while the stream
+    /// is pending it also schedules a wakeup after the
+    /// [pump interval](EventStream::set_pump_interval). Like the pump itself, the stream can't
+    /// leave the main thread.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures_core::Stream;
+    /// use sdl3::event::Event;
+    /// use std::pin::Pin;
+    ///
+    /// let sdl_context = sdl3::init().unwrap();
+    /// let mut event_pump = sdl_context.event_pump().unwrap();
+    /// let mut events = event_pump.stream();
+    ///
+    /// pollster::block_on(async {
+    ///     while let Some(event) = std::future::poll_fn(|cx| Pin::new(&mut events).poll_next(cx)).await {
+    ///         if let Event::Quit { .. } = event {
+    ///             break;
+    ///         }
+    ///     }
+    /// });
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn stream(&mut self) -> EventStream<'_> {
+        EventStream::new()
+    }
+
     #[inline]
     pub fn keyboard_state(&self) -> crate::keyboard::KeyboardState {
         crate::keyboard::KeyboardState::new(self)
@@ -2824,14 +4772,152 @@ impl Iterator for EventWaitTimeoutIterator<'_> {
     }
 }
 
+#[cfg(feature = "async")]
+struct EventStreamShared {
+    waker: Mutex<Option<Waker>>,
+    timer_armed: AtomicBool,
+}
+
+#[cfg(feature = "async")]
+impl EventStreamShared {
+    fn wake(&self) {
+        let waker = self.waker.lock().unwrap_or_else(|e| e.into_inner()).take();
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// A stream that yields events as they arrive, see [`EventPump::stream`].
+///
+/// [`EventPump::stream`]: crate::EventPump::stream
+#[cfg(feature = "async")]
+#[must_use = "streams are lazy and do nothing unless polled"]
+pub struct EventStream<'a> {
+    shared: Arc<EventStreamShared>,
+    pump_interval_ns: u64,
+    _marker: PhantomData<&'a mut crate::EventPump>,
+}
+
+#[cfg(feature = "async")]
+impl EventStream<'_> {
+    fn new() -> Self {
+        let shared = Arc::new(EventStreamShared {
+            waker: Mutex::new(None),
+            timer_armed: AtomicBool::new(false),
+        });
+        unsafe {
+            sys::events::SDL_AddEventWatch(
+                Some(event_stream_watch),
+                Arc::as_ptr(&shared) as *mut c_void,
+            )
+        };
+        EventStream {
+            shared,
+            pump_interval_ns: 5_000_000,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Sets how long a pending stream waits at most before pumping the event loop again, 5 ms
+    /// by default.
+    ///
+    /// Shorter intervals lower the input latency at the cost of more wakeups.
+    pub fn set_pump_interval(&mut self, interval: Duration) -> &mut Self {
+        self.pump_interval_ns = (interval.as_nanos() as u64).max(1);
+        self
+    }
+
+    fn arm_timer(&self) {
+        if self.shared.timer_armed.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        let userdata = Arc::into_raw(self.shared.clone()) as *mut c_void;
+        let timer = unsafe {
+            sys::timer::SDL_AddTimerNS(self.pump_interval_ns, Some(event_stream_timer), userdata)
+        };
+        if timer == 0 {
+            // The timer couldn't be created, reclaim its reference.
+            drop(unsafe { Arc::from_raw(userdata as *const EventStreamShared) });
+            self.shared.timer_armed.store(false, Ordering::Release);
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl Stream for EventStream<'_> {
+    type Item = Event;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Event>> {
+        let this = self.get_mut();
+
+        // Register the waker before polling, so an event pushed in between isn't missed.
+        *this.shared.waker.lock().unwrap_or_else(|e| e.into_inner()) = Some(cx.waker().clone());
+
+        match unsafe { poll_event() } {
+            Some(event) => Poll::Ready(Some(event)),
+            None => {
+                this.arm_timer();
+                if !this.shared.timer_armed.load(Ordering::Acquire) {
+                    // Without a timer nothing would pump the event loop.
+                    cx.waker().wake_by_ref();
+                }
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl Drop for EventStream<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            sys::events::SDL_RemoveEventWatch(
+                Some(event_stream_watch),
+                Arc::as_ptr(&self.shared) as *mut c_void,
+            );
+        }
+        // A pending timer is left to fire, it holds its own reference and only wakes the
+        // waker, which is gone by then. Removing it instead could race with the callback.
+        self.shared
+            .waker
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take();
+    }
+}
+
+#[cfg(feature = "async")]
+extern "C" fn event_stream_watch(
+    user_data: *mut c_void,
+    _event: *mut sys::events::SDL_Event,
+) -> bool {
+    let shared = unsafe { &*(user_data as *const EventStreamShared) };
+    shared.wake();
+    true
+}
+
+#[cfg(feature = "async")]
+extern "C" fn event_stream_timer(
+    user_data: *mut c_void,
+    _timer_id: sys::timer::SDL_TimerID,
+    _interval: u64,
+) -> u64 {
+    let shared = unsafe { Arc::from_raw(user_data as *const EventStreamShared) };
+    shared.timer_armed.store(false, Ordering::Release);
+    shared.wake();
+    0
+}
+
 #[cfg(test)]
 mod test {
     use crate::video::Display;
 
     use super::super::gamepad::{Axis, Button};
-    use super::super::joystick::HatState;
+    use super::super::joystick::{HatState, PowerLevel};
     use super::super::keyboard::{Keycode, Mod, Scancode};
     use super::super::mouse::{MouseButton, MouseState, MouseWheelDirection};
+    use super::super::pen::{PenAxis, PenInputFlags};
     use super::super::video::Orientation;
     use super::DisplayEvent;
     use super::Event;
@@ -3000,6 +5086,16 @@ mod test {
             let e2 = Event::from_ll(e.clone().to_ll().unwrap());
             assert_eq!(e, e2);
         }
+        {
+            let e = Event::JoyBatteryUpdated {
+                timestamp: 0,
+                which: 2,
+                state: PowerLevel::OnBattery,
+                percent: 15,
+            };
+            let e2 = Event::from_ll(e.clone().to_ll().unwrap());
+            assert_eq!(e, e2);
+        }
         {
             let e = Event::ControllerAxisMotion {
                 timestamp: 53,
@@ -3052,6 +5148,152 @@ mod test {
             let e2 = Event::from_ll(e.clone().to_ll().unwrap());
             assert_eq!(e, e2);
         }
+        {
+            let e = Event::CameraDeviceApproved {
+                timestamp: 12,
+                which: 2,
+            };
+            let e2 = Event::from_ll(e.clone().to_ll().unwrap());
+            assert_eq!(e, e2);
+        }
+        {
+            let e = Event::LocaleChanged { timestamp: 16 };
+            let e2 = Event::from_ll(e.clone().to_ll().unwrap());
+            assert_eq!(e, e2);
+        }
+        {
+            let e = Event::TextInput {
+                timestamp: 17,
+                window_id: 2,
+                text: "héllo".to_owned(),
+            };
+            let e2 = Event::from_ll(e.clone().to_ll().unwrap());
+            assert_eq!(e, e2);
+        }
+        {
+            let e = Event::TextEditing {
+                timestamp: 18,
+                window_id: 2,
+                text: "かな".to_owned(),
+                start: 1,
+                length: 1,
+            };
+            let e2 = Event::from_ll(e.clone().to_ll().unwrap());
+            assert_eq!(e, e2);
+        }
+        {
+            let e = Event::TextEditingCandidates {
+                timestamp: 18,
+                window_id: 2,
+                candidates: vec!["仮名".to_owned(), "かな".to_owned(), "カナ".to_owned()],
+                selected_candidate: Some(1),
+                horizontal: false,
+            };
+            let e2 = Event::from_ll(e.clone().to_ll().unwrap());
+            assert_eq!(e, e2);
+        }
+        {
+            let e = Event::DropFile {
+                timestamp: 19,
+                window_id: 3,
+                filename: "/tmp/file.txt".to_owned(),
+            };
+            let e2 = Event::from_ll(e.clone().to_ll().unwrap());
+            assert_eq!(e, e2);
+        }
+        {
+            let e = Event::FingerMotion {
+                timestamp: 20,
+                touch_id: 1,
+                finger_id: 2,
+                x: 0.25,
+                y: 0.5,
+                dx: 0.01,
+                dy: -0.01,
+                pressure: 1.0,
+            };
+            let e2 = Event::from_ll(e.clone().to_ll().unwrap());
+            assert_eq!(e, e2);
+        }
+        {
+            let e = Event::AudioDeviceAdded {
+                timestamp: 21,
+                which: 4,
+                iscapture: true,
+            };
+            let e2 = Event::from_ll(e.clone().to_ll().unwrap());
+            assert_eq!(e, e2);
+        }
+        {
+            let e = Event::AppWillEnterBackground { timestamp: 22 };
+            let e2 = Event::from_ll(e.clone().to_ll().unwrap());
+            assert_eq!(e, e2);
+        }
+        {
+            // Strings with a nul byte can't be represented.
+            let e = Event::TextInput {
+                timestamp: 23,
+                window_id: 2,
+                text: "a\0b".to_owned(),
+            };
+            assert!(e.to_ll().is_none());
+        }
+        {
+            let e = Event::PenDown {
+                timestamp: 13,
+                window_id: 1,
+                which: 4,
+                state: PenInputFlags::DOWN | PenInputFlags::ERASER_TIP,
+                x: 12.5,
+                y: 7.25,
+                eraser: true,
+            };
+            let e2 = Event::from_ll(e.clone().to_ll().unwrap());
+            assert_eq!(e, e2);
+        }
+        {
+            let e = Event::PenButtonUp {
+                timestamp: 14,
+                window_id: 1,
+                which: 4,
+                state: PenInputFlags::empty(),
+                x: 1.0,
+                y: 2.0,
+                button: 2,
+            };
+            let e2 = Event::from_ll(e.clone().to_ll().unwrap());
+            assert_eq!(e, e2);
+        }
+        {
+            let e = Event::PenAxis {
+                timestamp: 15,
+                window_id: 1,
+                which: 4,
+                state: PenInputFlags::DOWN,
+                x: 3.0,
+                y: 4.0,
+                axis: PenAxis::Pressure,
+                value: 0.75,
+            };
+            let e2 = Event::from_ll(e.clone().to_ll().unwrap());
+            assert_eq!(e, e2);
+        }
+    }
+
+    #[test]
+    fn test_is_from_pen() {
+        let finger = |touch_id| Event::FingerDown {
+            timestamp: 0,
+            touch_id,
+            finger_id: 0,
+            x: 0.5,
+            y: 0.5,
+            dx: 0.0,
+            dy: 0.0,
+            pressure: 1.0,
+        };
+        assert!(finger(crate::pen::PEN_TOUCH_ID).is_from_pen());
+        assert!(!finger(1).is_from_pen());
     }
 
     #[test]
@@ -3107,6 +5349,830 @@ mod test {
             panic!()
         }
     }
+
+    mod round_trip {
+        use super::super::super::gamepad::{Axis, Button};
+        use super::super::super::joystick::{HatState, PowerLevel};
+        use super::super::super::keyboard::{Keycode, Mod, Scancode};
+        use super::super::super::mouse::{MouseButton, MouseState, MouseWheelDirection};
+        use super::super::super::pen::{PenAxis, PenInputFlags};
+        use super::super::super::video::{Display, Orientation};
+        use super::super::{DisplayEvent, Event, WindowEvent};
+        use proptest::prelude::*;
+        use std::ffi::c_void;
+
+        // NaN never compares equal, so only finite values are generated.
+        fn coord() -> impl Strategy<Value = f32> {
+            -1.0e6f32..1.0e6f32
+        }
+
+        // Strings containing nul can't be converted.
+        fn text() -> impl Strategy<Value = String> {
+            "[^\u{0}]{0,32}"
+        }
+
+        fn window_event() -> impl Strategy<Value = WindowEvent> {
+            let (a, b) = (any::<i32>(), any::<i32>());
+            prop_oneof![
+                Just(WindowEvent::Shown),
+                Just(WindowEvent::Hidden),
+                Just(WindowEvent::Exposed),
+                (a.clone(), b.clone()).prop_map(|(x, y)| WindowEvent::Moved(x, y)),
+                (a.clone(), b.clone()).prop_map(|(w, h)| WindowEvent::Resized(w, h)),
+                (a.clone(), b.clone()).prop_map(|(w, h)| WindowEvent::PixelSizeChanged(w, h)),
+                Just(WindowEvent::Minimized),
+                Just(WindowEvent::Maximized),
+                Just(WindowEvent::Restored),
+                Just(WindowEvent::MouseEnter),
+                Just(WindowEvent::MouseLeave),
+                Just(WindowEvent::FocusGained),
+                Just(WindowEvent::FocusLost),
+                Just(WindowEvent::CloseRequested),
+                (a.clone(), b).prop_map(|(x, y)| WindowEvent::HitTest(x, y)),
+                Just(WindowEvent::ICCProfChanged),
+                a.prop_map(WindowEvent::DisplayChanged),
+            ]
+        }
+
+        fn display_event() -> impl Strategy<Value = DisplayEvent> {
+            prop_oneof![
+                prop::sample::select(vec![
+                    Orientation::Unknown,
+                    Orientation::Landscape,
+                    Orientation::LandscapeFlipped,
+                    Orientation::Portrait,
+                    Orientation::PortraitFlipped,
+                ])
+                .prop_map(DisplayEvent::Orientation),
+                Just(DisplayEvent::Added),
+                Just(DisplayEvent::Removed),
+                Just(DisplayEvent::Moved),
+                Just(DisplayEvent::DesktopModeChanged),
+                Just(DisplayEvent::CurrentModeChanged),
+                Just(DisplayEvent::ContentScaleChanged),
+            ]
+        }
+
+        fn keycode() -> impl Strategy<Value = Keycode> {
+            prop::sample::select(vec![
+                Keycode::Unknown,
+                Keycode::Return,
+                Keycode::Escape,
+                Keycode::Space,
+                Keycode::A,
+                Keycode::Z,
+                Keycode::_0,
+                Keycode::F12,
+                Keycode::Left,
+                Keycode::LShift,
+                Keycode::EndCall,
+            ])
+        }
+
+        fn scancode() -> impl Strategy<Value = Scancode> {
+            prop::sample::select(vec![
+                Scancode::Unknown,
+                Scancode::A,
+                Scancode::Z,
+                Scancode::_0,
+                Scancode::Return,
+                Scancode::F12,
+                Scancode::Left,
+                Scancode::LShift,
+                Scancode::EndCall,
+            ])
+        }
+
+        fn mouse_button() -> impl Strategy<Value = MouseButton> {
+            prop::sample::select(vec![
+                MouseButton::Unknown,
+                MouseButton::Left,
+                MouseButton::Middle,
+                MouseButton::Right,
+                MouseButton::X1,
+                MouseButton::X2,
+            ])
+        }
+
+        fn hat_state() -> impl Strategy<Value = HatState> {
+            prop::sample::select(vec![
+                HatState::Centered,
+                HatState::Up,
+                HatState::Right,
+                HatState::Down,
+                HatState::Left,
+                HatState::RightUp,
+                HatState::RightDown,
+                HatState::LeftUp,
+                HatState::LeftDown,
+            ])
+        }
+
+        fn power_level() -> impl Strategy<Value = PowerLevel> {
+            prop::sample::select(vec![
+                PowerLevel::Unknown,
+                PowerLevel::Error,
+                PowerLevel::OnBattery,
+                PowerLevel::NoBattery,
+                PowerLevel::Charging,
+                PowerLevel::Charged,
+            ])
+        }
+
+        fn axis() -> impl Strategy<Value = Axis> {
+            prop::sample::select(vec![
+                Axis::LeftX,
+                Axis::LeftY,
+                Axis::RightX,
+                Axis::RightY,
+                Axis::TriggerLeft,
+                Axis::TriggerRight,
+            ])
+        }
+
+        fn button() -> impl Strategy<Value = Button> {
+            prop::sample::select(vec![
+                Button::North,
+                Button::East,
+                Button::South,
+                Button::West,
+                Button::Back,
+                Button::Guide,
+                Button::Start,
+                Button::LeftStick,
+                Button::RightStick,
+                Button::LeftShoulder,
+                Button::RightShoulder,
+                Button::DPadUp,
+                Button::DPadDown,
+                Button::DPadLeft,
+                Button::DPadRight,
+                Button::Misc1,
+                Button::Misc2,
+                Button::Misc3,
+                Button::Misc4,
+                Button::Misc5,
+                Button::RightPaddle1,
+                Button::LeftPaddle1,
+                Button::RightPaddle2,
+                Button::LeftPaddle2,
+                Button::Touchpad,
+            ])
+        }
+
+        fn pen_axis() -> impl Strategy<Value = PenAxis> {
+            prop::sample::select(vec![
+                PenAxis::Pressure,
+                PenAxis::XTilt,
+                PenAxis::YTilt,
+                PenAxis::Distance,
+                PenAxis::Rotation,
+                PenAxis::Slider,
+                PenAxis::TangentialPressure,
+                PenAxis::Unknown,
+            ])
+        }
+
+        fn pen_state() -> impl Strategy<Value = PenInputFlags> {
+            any::<u32>().prop_map(PenInputFlags::from_bits_retain)
+        }
+
+        fn app_event() -> impl Strategy<Value = Event> {
+            any::<u64>().prop_flat_map(|timestamp| {
+                prop_oneof![
+                    Just(Event::Quit { timestamp }),
+                    Just(Event::AppTerminating { timestamp }),
+                    Just(Event::AppLowMemory { timestamp }),
+                    Just(Event::AppWillEnterBackground { timestamp }),
+                    Just(Event::AppDidEnterBackground { timestamp }),
+                    Just(Event::AppWillEnterForeground { timestamp }),
+                    Just(Event::AppDidEnterForeground { timestamp }),
+                    Just(Event::LocaleChanged { timestamp }),
+                    Just(Event::SystemThemeChanged { timestamp }),
+                    Just(Event::ClipboardUpdate { timestamp }),
+                    Just(Event::RenderTargetsReset { timestamp }),
+                    Just(Event::RenderDeviceReset { timestamp }),
+                ]
+            })
+        }
+
+        fn video_event() -> impl Strategy<Value = Event> {
+            prop_oneof![
+                (any::<u64>(), any::<u32>(), window_event()).prop_map(
+                    |(timestamp, window_id, win_event)| Event::Window {
+                        timestamp,
+                        window_id,
+                        win_event,
+                    }
+                ),
+                (any::<u64>(), any::<u32>(), display_event()).prop_map(
+                    |(timestamp, id, display_event)| Event::Display {
+                        timestamp,
+                        display: Display::from_ll(id),
+                        display_event,
+                    }
+                ),
+            ]
+        }
+
+        fn keyboard_event() -> impl Strategy<Value = Event> {
+            prop_oneof![
+                (
+                    any::<bool>(),
+                    any::<u64>(),
+                    any::<u32>(),
+                    keycode(),
+                    scancode(),
+                    any::<u16>(),
+                    any::<bool>(),
+                    any::<u32>(),
+                    any::<u16>(),
+                )
+                    .prop_map(
+                        |(
+                            down,
+                            timestamp,
+                            window_id,
+                            keycode,
+                            scancode,
+                            keymod,
+                            repeat,
+                            which,
+                            raw,
+                        )| {
+                            let keycode = Some(keycode);
+                            let scancode = Some(scancode);
+                            let keymod = Mod::from_bits_truncate(keymod);
+                            if down {
+                                Event::KeyDown {
+                                    timestamp,
+                                    window_id,
+                                    keycode,
+                                    scancode,
+                                    keymod,
+                                    repeat,
+                                    which,
+                                    raw,
+                                }
+                            } else {
+                                Event::KeyUp {
+                                    timestamp,
+                                    window_id,
+                                    keycode,
+                                    scancode,
+                                    keymod,
+                                    repeat,
+                                    which,
+                                    raw,
+                                }
+                            }
+                        }
+                    ),
+                (
+                    any::<u64>(),
+                    any::<u32>(),
+                    text(),
+                    any::<i32>(),
+                    any::<i32>()
+                )
+                    .prop_map(|(timestamp, window_id, text, start, length)| {
+                        Event::TextEditing {
+                            timestamp,
+                            window_id,
+                            text,
+                            start,
+                            length,
+                        }
+                    }),
+                (
+                    any::<u64>(),
+                    any::<u32>(),
+                    prop::collection::vec(text(), 0..5),
+                    prop::option::of(0usize..5),
+                    any::<bool>(),
+                )
+                    .prop_map(
+                        |(timestamp, window_id, candidates, selected_candidate, horizontal)| {
+                            Event::TextEditingCandidates {
+                                timestamp,
+                                window_id,
+                                candidates,
+                                selected_candidate,
+                                horizontal,
+                            }
+                        }
+                    ),
+                (any::<u64>(), any::<u32>(), text()).prop_map(|(timestamp, window_id, text)| {
+                    Event::TextInput {
+                        timestamp,
+                        window_id,
+                        text,
+                    }
+                }),
+            ]
+        }
+
+        fn mouse_event() -> impl Strategy<Value = Event> {
+            prop_oneof![
+                (
+                    any::<u64>(),
+                    any::<u32>(),
+                    any::<u32>(),
+                    any::<u32>(),
+                    coord(),
+                    coord(),
+                    coord(),
+                    coord(),
+                )
+                    .prop_map(
+                        |(timestamp, window_id, which, state, x, y, xrel, yrel)| {
+                            Event::MouseMotion {
+                                timestamp,
+                                window_id,
+                                which,
+                                mousestate: MouseState::from_sdl_state(state),
+                                x,
+                                y,
+                                xrel,
+                                yrel,
+                            }
+                        }
+                    ),
+                (
+                    any::<bool>(),
+                    any::<u64>(),
+                    any::<u32>(),
+                    any::<u32>(),
+                    mouse_button(),
+                    any::<u8>(),
+                    coord(),
+                    coord(),
+                )
+                    .prop_map(
+                        |(down, timestamp, window_id, which, mouse_btn, clicks, x, y)| {
+                            if down {
+                                Event::MouseButtonDown {
+                                    timestamp,
+                                    window_id,
+                                    which,
+                                    mouse_btn,
+                                    clicks,
+                                    x,
+                                    y,
+                                }
+                            } else {
+                                Event::MouseButtonUp {
+                                    timestamp,
+                                    window_id,
+                                    which,
+                                    mouse_btn,
+                                    clicks,
+                                    x,
+                                    y,
+                                }
+                            }
+                        }
+                    ),
+                (
+                    any::<u64>(),
+                    any::<u32>(),
+                    any::<u32>(),
+                    coord(),
+                    coord(),
+                    prop::sample::select(vec![
+                        MouseWheelDirection::Normal,
+                        MouseWheelDirection::Flipped,
+                    ]),
+                    coord(),
+                    coord(),
+                )
+                    .prop_map(
+                        |(timestamp, window_id, which, x, y, direction, mouse_x, mouse_y)| {
+                            Event::MouseWheel {
+                                timestamp,
+                                window_id,
+                                which,
+                                x,
+                                y,
+                                direction,
+                                mouse_x,
+                                mouse_y,
+                            }
+                        }
+                    ),
+            ]
+        }
+
+        fn joystick_event() -> impl Strategy<Value = Event> {
+            prop_oneof![
+                (any::<u64>(), any::<u32>(), any::<u8>(), any::<i16>()).prop_map(
+                    |(timestamp, which, axis_idx, value)| Event::JoyAxisMotion {
+                        timestamp,
+                        which,
+                        axis_idx,
+                        value,
+                    }
+                ),
+                (any::<u64>(), any::<u32>(), any::<u8>(), hat_state()).prop_map(
+                    |(timestamp, which, hat_idx, state)| Event::JoyHatMotion {
+                        timestamp,
+                        which,
+                        hat_idx,
+                        state,
+                    }
+                ),
+                (any::<bool>(), any::<u64>(), any::<u32>(), any::<u8>()).prop_map(
+                    |(down, timestamp, which, button_idx)| if down {
+                        Event::JoyButtonDown {
+                            timestamp,
+                            which,
+                            button_idx,
+                        }
+                    } else {
+                        Event::JoyButtonUp {
+                            timestamp,
+                            which,
+                            button_idx,
+                        }
+                    }
+                ),
+                (any::<u64>(), any::<u32>()).prop_flat_map(|(timestamp, which)| prop_oneof![
+                    Just(Event::JoyDeviceAdded { timestamp, which }),
+                    Just(Event::JoyDeviceRemoved { timestamp, which }),
+                ]),
+                (any::<u64>(), any::<u32>(), power_level(), any::<i32>()).prop_map(
+                    |(timestamp, which, state, percent)| Event::JoyBatteryUpdated {
+                        timestamp,
+                        which,
+                        state,
+                        percent,
+                    }
+                ),
+            ]
+        }
+
+        fn gamepad_event() -> impl Strategy<Value = Event> {
+            prop_oneof![
+                (any::<u64>(), any::<u32>(), axis(), any::<i16>()).prop_map(
+                    |(timestamp, which, axis, value)| Event::ControllerAxisMotion {
+                        timestamp,
+                        which,
+                        axis,
+                        value,
+                    }
+                ),
+                (any::<bool>(), any::<u64>(), any::<u32>(), button()).prop_map(
+                    |(down, timestamp, which, button)| if down {
+                        Event::ControllerButtonDown {
+                            timestamp,
+                            which,
+                            button,
+                        }
+                    } else {
+                        Event::ControllerButtonUp {
+                            timestamp,
+                            which,
+                            button,
+                        }
+                    }
+                ),
+                (any::<u64>(), any::<u32>()).prop_flat_map(|(timestamp, which)| prop_oneof![
+                    Just(Event::ControllerDeviceAdded { timestamp, which }),
+                    Just(Event::ControllerDeviceRemoved { timestamp, which }),
+                    Just(Event::ControllerDeviceRemapped { timestamp, which }),
+                ]),
+                (
+                    0..3,
+                    any::<u64>(),
+                    any::<u32>(),
+                    any::<i32>(),
+                    any::<i32>(),
+                    coord(),
+                    coord(),
+                    coord(),
+                )
+                    .prop_map(
+                        |(kind, timestamp, which, touchpad, finger, x, y, pressure)| match kind {
+                            0 => Event::ControllerTouchpadDown {
+                                timestamp,
+                                which,
+                                touchpad,
+                                finger,
+                                x,
+                                y,
+                                pressure,
+                            },
+                            1 => Event::ControllerTouchpadMotion {
+                                timestamp,
+                                which,
+                                touchpad,
+                                finger,
+                                x,
+                                y,
+                                pressure,
+                            },
+                            _ => Event::ControllerTouchpadUp {
+                                timestamp,
+                                which,
+                                touchpad,
+                                finger,
+                                x,
+                                y,
+                                pressure,
+                            },
+                        }
+                    ),
+            ]
+        }
+
+        fn touch_event() -> impl Strategy<Value = Event> {
+            (
+                0..3,
+                any::<u64>(),
+                any::<u64>(),
+                any::<u64>(),
+                coord(),
+                coord(),
+                coord(),
+                coord(),
+                coord(),
+            )
+                .prop_map(
+                    |(kind, timestamp, touch_id, finger_id, x, y, dx, dy, pressure)| match kind {
+                        0 => Event::FingerDown {
+                            timestamp,
+                            touch_id,
+                            finger_id,
+                            x,
+                            y,
+                            dx,
+                            dy,
+                            pressure,
+                        },
+                        1 => Event::FingerMotion {
+                            timestamp,
+                            touch_id,
+                            finger_id,
+                            x,
+                            y,
+                            dx,
+                            dy,
+                            pressure,
+                        },
+                        _ => Event::FingerUp {
+                            timestamp,
+                            touch_id,
+                            finger_id,
+                            x,
+                            y,
+                            dx,
+                            dy,
+                            pressure,
+                        },
+                    },
+                )
+        }
+
+        fn drop_event() -> impl Strategy<Value = Event> {
+            prop_oneof![
+                (any::<bool>(), any::<u64>(), any::<u32>(), text()).prop_map(
+                    |(file, timestamp, window_id, filename)| if file {
+                        Event::DropFile {
+                            timestamp,
+                            window_id,
+                            filename,
+                        }
+                    } else {
+                        Event::DropText {
+                            timestamp,
+                            window_id,
+                            filename,
+                        }
+                    }
+                ),
+                (any::<u64>(), any::<u32>()).prop_flat_map(|(timestamp, window_id)| prop_oneof![
+                    Just(Event::DropBegin {
+                        timestamp,
+                        window_id
+                    }),
+                    Just(Event::DropComplete {
+                        timestamp,
+                        window_id
+                    }),
+                ]),
+            ]
+        }
+
+        fn device_event() -> impl Strategy<Value = Event> {
+            prop_oneof![
+                (any::<bool>(), any::<u64>(), any::<u32>(), any::<bool>()).prop_map(
+                    |(added, timestamp, which, iscapture)| if added {
+                        Event::AudioDeviceAdded {
+                            timestamp,
+                            which,
+                            iscapture,
+                        }
+                    } else {
+                        Event::AudioDeviceRemoved {
+                            timestamp,
+                            which,
+                            iscapture,
+                        }
+                    }
+                ),
+                (any::<u64>(), any::<u32>()).prop_flat_map(|(timestamp, which)| prop_oneof![
+                    Just(Event::KeyboardAdded { timestamp, which }),
+                    Just(Event::KeyboardRemoved { timestamp, which }),
+                    Just(Event::MouseAdded { timestamp, which }),
+                    Just(Event::MouseRemoved { timestamp, which }),
+                    Just(Event::CameraDeviceAdded { timestamp, which }),
+                    Just(Event::CameraDeviceRemoved { timestamp, which }),
+                    Just(Event::CameraDeviceApproved { timestamp, which }),
+                    Just(Event::CameraDeviceDenied { timestamp, which }),
+                ]),
+            ]
+        }
+
+        fn pen_event() -> impl Strategy<Value = Event> {
+            (
+                0..8,
+                any::<u64>(),
+                any::<u32>(),
+                any::<u32>(),
+                pen_state(),
+                coord(),
+                coord(),
+                any::<bool>(),
+                any::<u8>(),
+                pen_axis(),
+                coord(),
+            )
+                .prop_map(
+                    |(
+                        kind,
+                        timestamp,
+                        window_id,
+                        which,
+                        state,
+                        x,
+                        y,
+                        eraser,
+                        button,
+                        axis,
+                        value,
+                    )| {
+                        match kind {
+                            0 => Event::PenProximityIn {
+                                timestamp,
+                                window_id,
+                                which,
+                            },
+                            1 => Event::PenProximityOut {
+                                timestamp,
+                                window_id,
+                                which,
+                            },
+                            2 => Event::PenDown {
+                                timestamp,
+                                window_id,
+                                which,
+                                state,
+                                x,
+                                y,
+                                eraser,
+                            },
+                            3 => Event::PenUp {
+                                timestamp,
+                                window_id,
+                                which,
+                                state,
+                                x,
+                                y,
+                                eraser,
+                            },
+                            4 => Event::PenButtonDown {
+                                timestamp,
+                                window_id,
+                                which,
+                                state,
+                                x,
+                                y,
+                                button,
+                            },
+                            5 => Event::PenButtonUp {
+                                timestamp,
+                                window_id,
+                                which,
+                                state,
+                                x,
+                                y,
+                                button,
+                            },
+                            6 => Event::PenMotion {
+                                timestamp,
+                                window_id,
+                                which,
+                                state,
+                                x,
+                                y,
+                            },
+                            _ => Event::PenAxis {
+                                timestamp,
+                                window_id,
+                                which,
+                                state,
+                                x,
+                                y,
+                                axis,
+                                value,
+                            },
+                        }
+                    },
+                )
+        }
+
+        fn user_event() -> impl Strategy<Value = Event> {
+            prop_oneof![
+                (
+                    any::<u64>(),
+                    any::<u32>(),
+                    // SDL_EVENT_USER up to, but excluding, SDL_EVENT_LAST
+                    0x8000u32..0xFFFF,
+                    any::<i32>(),
+                    any::<usize>(),
+                    any::<usize>(),
+                )
+                    .prop_map(
+                        |(timestamp, window_id, type_, code, data1, data2)| {
+                            Event::User {
+                                timestamp,
+                                window_id,
+                                type_,
+                                code,
+                                data1: data1 as *mut c_void,
+                                data2: data2 as *mut c_void,
+                            }
+                        }
+                    ),
+                // Event types that are unused in SDL 3.2
+                (any::<u64>(), 0x7000u32..0x7800)
+                    .prop_map(|(timestamp, type_)| Event::Unknown { timestamp, type_ }),
+            ]
+        }
+
+        fn any_event() -> impl Strategy<Value = Event> {
+            prop_oneof![
+                app_event(),
+                video_event(),
+                keyboard_event(),
+                mouse_event(),
+                joystick_event(),
+                gamepad_event(),
+                touch_event(),
+                drop_event(),
+                device_event(),
+                pen_event(),
+                user_event(),
+            ]
+        }
+
+        proptest! {
+            #[test]
+            fn test_to_from_ll(event in any_event()) {
+                let raw = event.to_ll().expect("event should convert");
+                prop_assert_eq!(Event::from_ll(raw), event);
+            }
+        }
+
+        #[test]
+        fn test_to_ll_unconvertible() {
+            let window = Event::Window {
+                timestamp: 0,
+                window_id: 1,
+                win_event: WindowEvent::None,
+            };
+            assert!(window.to_ll().is_none());
+
+            let display = Event::Display {
+                timestamp: 0,
+                display: Display::from_ll(1),
+                display_event: DisplayEvent::None,
+            };
+            assert!(display.to_ll().is_none());
+
+            let key = Event::KeyDown {
+                timestamp: 0,
+                window_id: 1,
+                keycode: None,
+                scancode: Some(Scancode::A),
+                keymod: Mod::empty(),
+                repeat: false,
+                which: 0,
+                raw: 0,
+            };
+            assert!(key.to_ll().is_none());
+        }
+    }
 }
 
 /// A sendible type that can push events to the event queue.
@@ -3118,9 +6184,30 @@ impl EventSender {
     /// Pushes an event to the event queue.
     #[doc(alias = "SDL_PushEvent")]
     pub fn push_event(&self, event: Event) -> Result<(), Error> {
-        match event.to_ll() {
+        let mut memory = Vec::new();
+        match event.to_ll_with(Some(&mut memory)) {
             Some(mut raw_event) => {
+                // Handed to the queue before pushing, as the event may be polled right away.
+                let addresses: Vec<usize> = memory.iter().map(EventMemory::address).collect();
+                queued_event_memory().extend(memory.into_iter().map(|memory| {
+                    let queued = QueuedMemory {
+                        pushed: false,
+                        _memory: memory,
+                    };
+                    (queued._memory.address(), queued)
+                }));
+
                 let ok = unsafe { sys::events::SDL_PushEvent(&mut raw_event) };
+                let mut queued = queued_event_memory();
+                for address in addresses {
+                    if ok {
+                        if let Some(queued) = queued.get_mut(&address) {
+                            queued.pushed = true;
+                        }
+                    } else {
+                        queued.remove(&address);
+                    }
+                }
                 if ok {
                     Ok(())
                 } else {
@@ -44,6 +44,9 @@ macro_rules! cstring_path {
     };
 }
 
+pub(crate) use cstring_path;
+pub(crate) use path_cstring;
+
 #[doc(alias = "SDL_CopyFile")]
 pub fn copy_file(
     old_path: impl AsRef<Path>,
@@ -123,6 +126,10 @@ pub struct PathInfo {
 }
 
 impl PathInfo {
+    pub(crate) fn from_ll(internal: SDL_PathInfo) -> Self {
+        Self { internal }
+    }
+
     fn path_type(&self) -> PathType {
         self.internal.r#type as PathType
     }
@@ -284,7 +291,7 @@ pub struct GlobResults<'a> {
 }
 
 impl GlobResults<'_> {
-    fn new(internal: *mut *mut c_char, count: isize) -> Self {
+    pub(crate) fn new(internal: *mut *mut c_char, count: isize) -> Self {
         Self {
             internal,
             count,
@@ -491,6 +491,7 @@ impl GamepadType {
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 #[repr(i32)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Axis {
     LeftX = sys::gamepad::SDL_GAMEPAD_AXIS_LEFTX.0,
     LeftY = sys::gamepad::SDL_GAMEPAD_AXIS_LEFTY.0,
@@ -563,6 +564,7 @@ impl From<Axis> for u8 {
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 #[repr(i32)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Button {
     North = sys::gamepad::SDL_GAMEPAD_BUTTON_NORTH.0,
     East = sys::gamepad::SDL_GAMEPAD_BUTTON_EAST.0,
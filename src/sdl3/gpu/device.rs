@@ -220,6 +220,24 @@ impl Device {
         ComputePipelineBuilder::new(self)
     }
 
+    /// Returns the name of the backend driver, e.g. `"vulkan"`.
+    ///
+    /// SDL 3.2 has no properties for GPU devices, this is the only information about the
+    /// device besides its [shader formats](Self::get_shader_formats).
+    #[doc(alias = "SDL_GetGPUDeviceDriver")]
+    pub fn get_driver(&self) -> String {
+        unsafe {
+            let driver = sys::gpu::SDL_GetGPUDeviceDriver(self.raw());
+            if driver.is_null() {
+                String::new()
+            } else {
+                std::ffi::CStr::from_ptr(driver)
+                    .to_string_lossy()
+                    .into_owned()
+            }
+        }
+    }
+
     #[doc(alias = "SDL_GetGPUShaderFormats")]
     pub fn get_shader_formats(&self) -> ShaderFormat {
         unsafe { std::mem::transmute(sys::gpu::SDL_GetGPUShaderFormats(self.raw())) }
@@ -81,3 +81,32 @@ impl Display for Guid {
         write!(f, "{}", self.string())
     }
 }
+
+/// Serialized as the usual hex string in human readable formats, and as 16 bytes otherwise.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Guid {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.string())
+        } else {
+            self.raw.data.serialize(serializer)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Guid {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Guid, D::Error> {
+        use serde::de::Error;
+
+        if deserializer.is_human_readable() {
+            let guid = String::deserialize(deserializer)?;
+            Guid::from_string(&guid).map_err(D::Error::custom)
+        } else {
+            let data = <[u8; 16]>::deserialize(deserializer)?;
+            Ok(Guid {
+                raw: SDL_GUID { data },
+            })
+        }
+    }
+}
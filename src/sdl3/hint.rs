@@ -1,15 +1,268 @@
+//! Configuration hints
+//!
+//! Hints can be set through the raw [`set`] and [`get`] functions with a name from [`names`], or
+//! through the typed [`Hint`] constants in this module, which check the value at compile time:
+//!
+//! ```rust,no_run
+//! use sdl3::hint::{self, RenderLineMethod};
+//!
+//! hint::RENDER_VSYNC.set(true);
+//! hint::RENDER_LINE_METHOD.set(RenderLineMethod::Geometry);
+//! hint::AUDIO_DEVICE_STREAM_NAME.set("Music");
+//! assert_eq!(hint::RENDER_VSYNC.get(), Some(true));
+//! ```
+
+use crate::get_error;
 use crate::sys;
+use crate::Error;
 use libc::c_char;
-use std::ffi::{CStr, CString};
+use std::ffi::{c_void, CStr, CString};
+use std::marker::PhantomData;
 
 pub mod names;
+mod typed;
+
+pub use self::typed::*;
 
-pub enum Hint {
+/// The priority of a hint value, which controls whether it replaces an existing value.
+///
+/// Values replace existing values of their priority and lower. Environment variables are
+/// considered to have override priority.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum HintPriority {
     Default,
     Normal,
     Override,
 }
 
+impl HintPriority {
+    pub fn to_ll(self) -> sys::hints::SDL_HintPriority {
+        match self {
+            HintPriority::Default => sys::hints::SDL_HINT_DEFAULT,
+            HintPriority::Normal => sys::hints::SDL_HINT_NORMAL,
+            HintPriority::Override => sys::hints::SDL_HINT_OVERRIDE,
+        }
+    }
+}
+
+/// A type that hint values can be converted from and to.
+pub trait HintValue: Sized {
+    /// The string SDL is given for this value.
+    fn to_hint(&self) -> String;
+
+    /// Parses a hint string, returns `None` if SDL would not understand it either.
+    fn from_hint(value: &str) -> Option<Self>;
+}
+
+impl HintValue for bool {
+    fn to_hint(&self) -> String {
+        if *self { "1" } else { "0" }.to_owned()
+    }
+
+    /// Parses the value the way `SDL_GetHintBoolean` does: `"0"` and `"false"` are `false`,
+    /// every other non-empty string is `true`.
+    fn from_hint(value: &str) -> Option<bool> {
+        if value.is_empty() {
+            None
+        } else {
+            Some(value != "0" && !value.eq_ignore_ascii_case("false"))
+        }
+    }
+}
+
+macro_rules! impl_hint_value_from_str {
+    ($($ty:ty),*) => {
+        $(
+            impl HintValue for $ty {
+                fn to_hint(&self) -> String {
+                    self.to_string()
+                }
+
+                fn from_hint(value: &str) -> Option<$ty> {
+                    value.trim().parse().ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_hint_value_from_str!(i32, i64, u32, u64, f32);
+
+impl HintValue for String {
+    fn to_hint(&self) -> String {
+        self.clone()
+    }
+
+    fn from_hint(value: &str) -> Option<String> {
+        Some(value.to_owned())
+    }
+}
+
+/// A hint together with the type of its value.
+///
+/// Every hint SDL knows has a constant in this module, named like its entry in [`names`].
+pub struct Hint<T> {
+    name: &'static str,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for Hint<T> {
+    fn clone(&self) -> Hint<T> {
+        *self
+    }
+}
+
+impl<T> Copy for Hint<T> {}
+
+impl<T> std::fmt::Debug for Hint<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_tuple("Hint").field(&self.name).finish()
+    }
+}
+
+impl<T: HintValue> Hint<T> {
+    /// Describes the hint `name`, e.g. one that isn't listed in [`names`] yet.
+    pub const fn new(name: &'static str) -> Hint<T> {
+        Hint {
+            name,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The name of the hint, as found in [`names`].
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Sets the hint with normal priority.
+    ///
+    /// Returns `false` if a value with higher priority is already set.
+    #[doc(alias = "SDL_SetHint")]
+    pub fn set(&self, value: impl Into<T>) -> bool {
+        set(self.name, &value.into().to_hint())
+    }
+
+    /// Sets the hint with a specific priority.
+    ///
+    /// Returns `false` if a value with higher priority is already set.
+    #[doc(alias = "SDL_SetHintWithPriority")]
+    pub fn set_with_priority(&self, value: impl Into<T>, priority: HintPriority) -> bool {
+        set_with_priority(self.name, &value.into().to_hint(), &priority)
+    }
+
+    /// Returns the current value, or `None` if the hint isn't set or its value can't be parsed.
+    #[doc(alias = "SDL_GetHint")]
+    pub fn get(&self) -> Option<T> {
+        get(self.name).and_then(|value| T::from_hint(&value))
+    }
+
+    /// Resets the hint to its environment variable, or unsets it if there is none.
+    #[doc(alias = "SDL_ResetHint")]
+    pub fn reset(&self) -> bool {
+        reset(self.name)
+    }
+
+    /// Calls `callback` with the new value whenever the hint changes, and once right away with
+    /// the current value.
+    ///
+    /// The callback is removed when the returned guard is dropped.
+    #[doc(alias = "SDL_AddHintCallback")]
+    pub fn add_callback<F>(&self, mut callback: F) -> Result<HintCallback, Error>
+    where
+        F: FnMut(Option<T>) + Send + 'static,
+    {
+        add_hint_callback(self.name, move |_, _, new_value| {
+            callback(new_value.and_then(T::from_hint))
+        })
+    }
+}
+
+type HintCallbackFn = Box<dyn FnMut(&str, Option<&str>, Option<&str>) + Send>;
+
+/// Keeps a hint callback registered, see [`add_hint_callback`].
+///
+/// The callback is removed when this is dropped.
+#[must_use = "the callback is removed when the guard is dropped"]
+pub struct HintCallback {
+    name: CString,
+    callback: Box<HintCallbackFn>,
+}
+
+impl HintCallback {
+    fn userdata(&mut self) -> *mut c_void {
+        &mut *self.callback as *mut HintCallbackFn as *mut c_void
+    }
+}
+
+impl Drop for HintCallback {
+    #[doc(alias = "SDL_RemoveHintCallback")]
+    fn drop(&mut self) {
+        let userdata = self.userdata();
+        unsafe {
+            sys::hints::SDL_RemoveHintCallback(
+                self.name.as_ptr(),
+                Some(hint_callback_marshall),
+                userdata,
+            )
+        }
+    }
+}
+
+impl std::fmt::Debug for HintCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("HintCallback")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+unsafe extern "C" fn hint_callback_marshall(
+    userdata: *mut c_void,
+    name: *const c_char,
+    old_value: *const c_char,
+    new_value: *const c_char,
+) {
+    let to_str = |s: *const c_char| {
+        if s.is_null() {
+            None
+        } else {
+            CStr::from_ptr(s).to_str().ok()
+        }
+    };
+    let callback = &mut *(userdata as *mut HintCallbackFn);
+    callback(
+        to_str(name).unwrap_or_default(),
+        to_str(old_value),
+        to_str(new_value),
+    );
+}
+
+/// Calls `callback` with the hint name, the old value and the new value whenever the hint
+/// `name` changes. It is also called once right away, with the current value as the new value.
+///
+/// The callback may be called from any thread that changes the hint. It is removed when the
+/// returned guard is dropped.
+#[doc(alias = "SDL_AddHintCallback")]
+pub fn add_hint_callback<F>(name: &str, callback: F) -> Result<HintCallback, Error>
+where
+    F: FnMut(&str, Option<&str>, Option<&str>) + Send + 'static,
+{
+    let mut guard = HintCallback {
+        name: CString::new(name).unwrap(),
+        callback: Box::new(Box::new(callback)),
+    };
+    let userdata = guard.userdata();
+    let added = unsafe {
+        sys::hints::SDL_AddHintCallback(guard.name.as_ptr(), Some(hint_callback_marshall), userdata)
+    };
+    if added {
+        Ok(guard)
+    } else {
+        // Removing a callback that was never added does nothing, so the guard can be dropped.
+        Err(get_error())
+    }
+}
+
 /// A hint that specifies whether a fullscreen [Window](../video/Window.t.html) will be
 /// minimized if key focus is lost.
 ///
@@ -39,7 +292,7 @@ pub fn set_video_minimize_on_focus_loss(value: bool) -> bool {
 ///
 /// # Example
 /// ```rust,no_run
-/// sdl3::hint::set_video_minimize_on_focus_loss_with_priority(false, &sdl3::hint::Hint::Override);
+/// sdl3::hint::set_video_minimize_on_focus_loss_with_priority(false, &sdl3::hint::HintPriority::Override);
 /// ```
 ///
 /// * `value`: `true` to enable minimizing of the Window if it loses key focus when in fullscreen mode,
@@ -47,7 +300,10 @@ pub fn set_video_minimize_on_focus_loss(value: bool) -> bool {
 /// * `priority`: The priority controls the behavior when setting a hint that already has a value.
 ///               Hints will replace existing hints of their priority and lower.
 ///               Environment variables are considered to have override priority.
-pub fn set_video_minimize_on_focus_loss_with_priority(value: bool, priority: &Hint) -> bool {
+pub fn set_video_minimize_on_focus_loss_with_priority(
+    value: bool,
+    priority: &HintPriority,
+) -> bool {
     set_with_priority(
         names::VIDEO_MINIMIZE_ON_FOCUS_LOSS,
         if value { "1" } else { "0" },
@@ -111,21 +367,28 @@ pub fn get(name: &str) -> Option<String> {
 }
 
 #[doc(alias = "SDL_SetHintWithPriority")]
-pub fn set_with_priority(name: &str, value: &str, priority: &Hint) -> bool {
+pub fn set_with_priority(name: &str, value: &str, priority: &HintPriority) -> bool {
     let name = CString::new(name).unwrap();
     let value = CString::new(value).unwrap();
 
-    let priority_val = match *priority {
-        Hint::Normal => sys::hints::SDL_HINT_NORMAL,
-        Hint::Override => sys::hints::SDL_HINT_OVERRIDE,
-        Hint::Default => sys::hints::SDL_HINT_DEFAULT,
-    };
-
     unsafe {
         sys::hints::SDL_SetHintWithPriority(
             name.as_ptr() as *const c_char,
             value.as_ptr() as *const c_char,
-            priority_val,
+            priority.to_ll(),
         )
     }
 }
+
+/// Resets a hint to its environment variable, or unsets it if there is none.
+#[doc(alias = "SDL_ResetHint")]
+pub fn reset(name: &str) -> bool {
+    let name = CString::new(name).unwrap();
+    unsafe { sys::hints::SDL_ResetHint(name.as_ptr() as *const c_char) }
+}
+
+/// Resets all hints to their environment variables, or unsets them if there are none.
+#[doc(alias = "SDL_ResetHints")]
+pub fn reset_all() {
+    unsafe { sys::hints::SDL_ResetHints() }
+}
@@ -0,0 +1,472 @@
+//! Typed descriptors for every hint in [`names`].
+//!
+//! Each constant pairs a hint name with the type of its value, so that e.g.
+//! `RENDER_VSYNC.set(true)` or `RENDER_LINE_METHOD.set(RenderLineMethod::Geometry)` can't be
+//! given a value SDL would silently ignore. Hints that take free-form strings, lists or driver
+//! names are typed as [`String`].
+
+use super::{names, Hint, HintValue};
+use crate::audio::AudioFormat;
+
+macro_rules! hint_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $value:literal $(| $alias:literal)*,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+        }
+
+        impl HintValue for $name {
+            fn to_hint(&self) -> String {
+                match self {
+                    $($name::$variant => $value,)*
+                }
+                .to_owned()
+            }
+
+            fn from_hint(value: &str) -> Option<$name> {
+                match value {
+                    $($value $(| $alias)* => Some($name::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+hint_enum! {
+    /// The iOS audio session category, see [`AUDIO_CATEGORY`].
+    pub enum AudioCategory {
+        /// Audio is muted by the silent switch and mixed with other apps.
+        Ambient = "ambient",
+        /// Audio keeps playing when the silent switch is on.
+        Playback = "playback",
+    }
+}
+
+hint_enum! {
+    /// A setting that can also be left for SDL to decide.
+    pub enum AutoToggle {
+        Off = "0",
+        On = "1",
+        Auto = "auto",
+    }
+}
+
+hint_enum! {
+    /// How verbosely events pushed onto the queue are logged, see [`EVENT_LOGGING`].
+    pub enum EventLogging {
+        Off = "0",
+        /// Log all events except the really spammy ones.
+        Most = "1",
+        /// Also log mouse and finger motion events.
+        All = "2",
+    }
+}
+
+hint_enum! {
+    /// The home indicator bar on iOS, see [`IOS_HIDE_HOME_INDICATOR`].
+    pub enum HomeIndicator {
+        Visible = "0",
+        /// Hidden, and shown when the screen is touched.
+        Hidden = "1",
+        /// Dimmed, the first swipe makes it visible.
+        Dimmed = "2",
+    }
+}
+
+hint_enum! {
+    /// Which Option keys are remapped to Alt on macOS, see [`MAC_OPTION_AS_ALT`].
+    pub enum MacOptionAsAlt {
+        None = "none",
+        OnlyLeft = "only_left",
+        OnlyRight = "only_right",
+        Both = "both",
+    }
+}
+
+hint_enum! {
+    /// How the renderer draws lines, see [`RENDER_LINE_METHOD`].
+    pub enum RenderLineMethod {
+        /// Bresenham's line algorithm.
+        Default = "0",
+        /// The driver point API using Bresenham's line algorithm.
+        Points = "1",
+        /// The driver line API.
+        Lines = "2",
+        /// The driver geometry API.
+        Geometry = "3",
+    }
+}
+
+hint_enum! {
+    /// The scheduler policy used by thread priorities with pthreads, see
+    /// [`THREAD_PRIORITY_POLICY`].
+    pub enum ThreadPriorityPolicy {
+        Current = "current",
+        Other = "other",
+        Fifo = "fifo",
+        RoundRobin = "rr",
+    }
+}
+
+hint_enum! {
+    /// How emulated video modes are scaled on Wayland, see [`VIDEO_WAYLAND_MODE_SCALING`].
+    pub enum WaylandModeScaling {
+        Aspect = "aspect",
+        Stretch = "stretch",
+        None = "none",
+    }
+}
+
+hint_enum! {
+    /// Which PS Vita touchpad generates mouse events, see [`VITA_TOUCH_MOUSE_DEVICE`].
+    pub enum VitaTouchMouseDevice {
+        Front = "0",
+        Back = "1",
+        Both = "2",
+    }
+}
+
+hint_enum! {
+    /// How the fact chunk of WAVE files is handled, see [`WAVE_FACT_CHUNK`].
+    pub enum WaveFactChunk {
+        Truncate = "truncate",
+        Strict = "strict",
+        IgnoreZero = "ignorezero",
+        Ignore = "ignore",
+    }
+}
+
+hint_enum! {
+    /// How the RIFF chunk size of WAVE files is handled, see [`WAVE_RIFF_CHUNK_SIZE`].
+    pub enum WaveRiffChunkSize {
+        Force = "force",
+        IgnoreZero = "ignorezero",
+        Ignore = "ignore",
+        Maximum = "maximum",
+    }
+}
+
+hint_enum! {
+    /// How truncated WAVE files are handled, see [`WAVE_TRUNCATION`].
+    pub enum WaveTruncation {
+        VeryStrict = "verystrict",
+        Strict = "strict",
+        DropFrame = "dropframe",
+        DropBlock = "dropblock",
+    }
+}
+
+hint_enum! {
+    /// When a window is cleared on `WM_ERASEBKGND`, see [`WINDOWS_ERASE_BACKGROUND_MODE`].
+    pub enum EraseBackgroundMode {
+        Never = "0" | "never",
+        Initial = "1" | "initial",
+        Always = "2" | "always",
+    }
+}
+
+hint_enum! {
+    /// What happens on a failed assertion, see [`ASSERT`].
+    pub enum AssertBehavior {
+        Abort = "abort",
+        Break = "break",
+        Retry = "retry",
+        Ignore = "ignore",
+        AlwaysIgnore = "always_ignore",
+    }
+}
+
+impl HintValue for AudioFormat {
+    fn to_hint(&self) -> String {
+        match self {
+            AudioFormat::UNKNOWN => "",
+            AudioFormat::U8 => "U8",
+            AudioFormat::S8 => "S8",
+            AudioFormat::S16LE => "S16LE",
+            AudioFormat::S16BE => "S16BE",
+            AudioFormat::S32LE => "S32LE",
+            AudioFormat::S32BE => "S32BE",
+            AudioFormat::F32LE => "F32LE",
+            AudioFormat::F32BE => "F32BE",
+        }
+        .to_owned()
+    }
+
+    fn from_hint(value: &str) -> Option<AudioFormat> {
+        match value {
+            "U8" => Some(AudioFormat::U8),
+            "S8" => Some(AudioFormat::S8),
+            "S16LE" => Some(AudioFormat::S16LE),
+            "S16BE" => Some(AudioFormat::S16BE),
+            "S16" => Some(AudioFormat::s16_sys()),
+            "S32LE" => Some(AudioFormat::S32LE),
+            "S32BE" => Some(AudioFormat::S32BE),
+            "S32" => Some(AudioFormat::s32_sys()),
+            "F32LE" => Some(AudioFormat::F32LE),
+            "F32BE" => Some(AudioFormat::F32BE),
+            "F32" => Some(AudioFormat::f32_sys()),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! hints {
+    ($($name:ident: $ty:ty,)*) => {
+        $(
+            #[doc = concat!("Typed [`names::", stringify!($name), "`].")]
+            pub const $name: Hint<$ty> = Hint::new(names::$name);
+        )*
+    };
+}
+
+hints! {
+    ALLOW_ALT_TAB_WHILE_GRABBED: bool,
+    ANDROID_ALLOW_RECREATE_ACTIVITY: bool,
+    ANDROID_BLOCK_ON_PAUSE: bool,
+    ANDROID_LOW_LATENCY_AUDIO: bool,
+    ANDROID_TRAP_BACK_BUTTON: bool,
+    APP_ID: String,
+    APP_NAME: String,
+    APPLE_TV_CONTROLLER_UI_EVENTS: bool,
+    APPLE_TV_REMOTE_ALLOW_ROTATION: bool,
+    AUDIO_ALSA_DEFAULT_DEVICE: String,
+    AUDIO_ALSA_DEFAULT_PLAYBACK_DEVICE: String,
+    AUDIO_ALSA_DEFAULT_RECORDING_DEVICE: String,
+    AUDIO_CATEGORY: AudioCategory,
+    AUDIO_CHANNELS: i32,
+    AUDIO_DEVICE_APP_ICON_NAME: String,
+    AUDIO_DEVICE_SAMPLE_FRAMES: i32,
+    AUDIO_DEVICE_STREAM_NAME: String,
+    AUDIO_DEVICE_STREAM_ROLE: String,
+    AUDIO_DISK_INPUT_FILE: String,
+    AUDIO_DISK_OUTPUT_FILE: String,
+    AUDIO_DISK_TIMESCALE: f32,
+    AUDIO_DRIVER: String,
+    AUDIO_DUMMY_TIMESCALE: f32,
+    AUDIO_FORMAT: AudioFormat,
+    AUDIO_FREQUENCY: i32,
+    AUDIO_INCLUDE_MONITORS: bool,
+    AUTO_UPDATE_JOYSTICKS: bool,
+    AUTO_UPDATE_SENSORS: bool,
+    BMP_SAVE_LEGACY_FORMAT: bool,
+    CAMERA_DRIVER: String,
+    CPU_FEATURE_MASK: String,
+    JOYSTICK_DIRECTINPUT: bool,
+    FILE_DIALOG_DRIVER: String,
+    DISPLAY_USABLE_BOUNDS: String,
+    EMSCRIPTEN_ASYNCIFY: bool,
+    EMSCRIPTEN_CANVAS_SELECTOR: String,
+    EMSCRIPTEN_KEYBOARD_ELEMENT: String,
+    ENABLE_SCREEN_KEYBOARD: AutoToggle,
+    EVDEV_DEVICES: String,
+    EVENT_LOGGING: EventLogging,
+    FORCE_RAISEWINDOW: bool,
+    FRAMEBUFFER_ACCELERATION: String,
+    GAMECONTROLLERCONFIG: String,
+    GAMECONTROLLERCONFIG_FILE: String,
+    GAMECONTROLLERTYPE: String,
+    GAMECONTROLLER_IGNORE_DEVICES: String,
+    GAMECONTROLLER_IGNORE_DEVICES_EXCEPT: String,
+    GAMECONTROLLER_SENSOR_FUSION: String,
+    GDK_TEXTINPUT_DEFAULT_TEXT: String,
+    GDK_TEXTINPUT_DESCRIPTION: String,
+    GDK_TEXTINPUT_MAX_LENGTH: i32,
+    GDK_TEXTINPUT_SCOPE: i32,
+    GDK_TEXTINPUT_TITLE: String,
+    HIDAPI_LIBUSB: bool,
+    HIDAPI_LIBUSB_WHITELIST: bool,
+    HIDAPI_UDEV: bool,
+    GPU_DRIVER: String,
+    HIDAPI_ENUMERATE_ONLY_CONTROLLERS: bool,
+    HIDAPI_IGNORE_DEVICES: String,
+    IME_IMPLEMENTED_UI: String,
+    IOS_HIDE_HOME_INDICATOR: HomeIndicator,
+    JOYSTICK_ALLOW_BACKGROUND_EVENTS: bool,
+    JOYSTICK_ARCADESTICK_DEVICES: String,
+    JOYSTICK_ARCADESTICK_DEVICES_EXCLUDED: String,
+    JOYSTICK_BLACKLIST_DEVICES: String,
+    JOYSTICK_BLACKLIST_DEVICES_EXCLUDED: String,
+    JOYSTICK_DEVICE: String,
+    JOYSTICK_ENHANCED_REPORTS: AutoToggle,
+    JOYSTICK_FLIGHTSTICK_DEVICES: String,
+    JOYSTICK_FLIGHTSTICK_DEVICES_EXCLUDED: String,
+    JOYSTICK_GAMEINPUT: bool,
+    JOYSTICK_GAMECUBE_DEVICES: String,
+    JOYSTICK_GAMECUBE_DEVICES_EXCLUDED: String,
+    JOYSTICK_HIDAPI: bool,
+    JOYSTICK_HIDAPI_COMBINE_JOY_CONS: bool,
+    JOYSTICK_HIDAPI_GAMECUBE: bool,
+    JOYSTICK_HIDAPI_GAMECUBE_RUMBLE_BRAKE: bool,
+    JOYSTICK_HIDAPI_JOY_CONS: bool,
+    JOYSTICK_HIDAPI_JOYCON_HOME_LED: f32,
+    JOYSTICK_HIDAPI_LUNA: bool,
+    JOYSTICK_HIDAPI_NINTENDO_CLASSIC: bool,
+    JOYSTICK_HIDAPI_PS3: bool,
+    JOYSTICK_HIDAPI_PS3_SIXAXIS_DRIVER: bool,
+    JOYSTICK_HIDAPI_PS4: bool,
+    JOYSTICK_HIDAPI_PS4_REPORT_INTERVAL: i32,
+    JOYSTICK_HIDAPI_PS5: bool,
+    JOYSTICK_HIDAPI_PS5_PLAYER_LED: bool,
+    JOYSTICK_HIDAPI_SHIELD: bool,
+    JOYSTICK_HIDAPI_STADIA: bool,
+    JOYSTICK_HIDAPI_STEAM: bool,
+    JOYSTICK_HIDAPI_STEAM_HOME_LED: f32,
+    JOYSTICK_HIDAPI_STEAMDECK: bool,
+    JOYSTICK_HIDAPI_STEAM_HORI: bool,
+    JOYSTICK_HIDAPI_SWITCH: bool,
+    JOYSTICK_HIDAPI_SWITCH_HOME_LED: f32,
+    JOYSTICK_HIDAPI_SWITCH_PLAYER_LED: bool,
+    JOYSTICK_HIDAPI_VERTICAL_JOY_CONS: bool,
+    JOYSTICK_HIDAPI_WII: bool,
+    JOYSTICK_HIDAPI_WII_PLAYER_LED: bool,
+    JOYSTICK_HIDAPI_XBOX: bool,
+    JOYSTICK_HIDAPI_XBOX_360: bool,
+    JOYSTICK_HIDAPI_XBOX_360_PLAYER_LED: bool,
+    JOYSTICK_HIDAPI_XBOX_360_WIRELESS: bool,
+    JOYSTICK_HIDAPI_XBOX_ONE: bool,
+    JOYSTICK_HIDAPI_XBOX_ONE_HOME_LED: f32,
+    JOYSTICK_IOKIT: bool,
+    JOYSTICK_LINUX_CLASSIC: bool,
+    JOYSTICK_LINUX_DEADZONES: bool,
+    JOYSTICK_LINUX_DIGITAL_HATS: bool,
+    JOYSTICK_LINUX_HAT_DEADZONES: bool,
+    JOYSTICK_MFI: bool,
+    JOYSTICK_RAWINPUT: bool,
+    JOYSTICK_RAWINPUT_CORRELATE_XINPUT: bool,
+    JOYSTICK_ROG_CHAKRAM: bool,
+    JOYSTICK_THREAD: bool,
+    JOYSTICK_THROTTLE_DEVICES: String,
+    JOYSTICK_THROTTLE_DEVICES_EXCLUDED: String,
+    JOYSTICK_WGI: bool,
+    JOYSTICK_WHEEL_DEVICES: String,
+    JOYSTICK_WHEEL_DEVICES_EXCLUDED: String,
+    JOYSTICK_ZERO_CENTERED_DEVICES: String,
+    KEYCODE_OPTIONS: String,
+    KMSDRM_DEVICE_INDEX: i32,
+    KMSDRM_REQUIRE_DRM_MASTER: bool,
+    LOGGING: String,
+    MAC_BACKGROUND_APP: bool,
+    MAC_CTRL_CLICK_EMULATE_RIGHT_CLICK: bool,
+    MAC_OPENGL_ASYNC_DISPATCH: bool,
+    MAC_OPTION_AS_ALT: MacOptionAsAlt,
+    MAC_SCROLL_MOMENTUM: bool,
+    MAIN_CALLBACK_RATE: String,
+    MOUSE_AUTO_CAPTURE: bool,
+    MOUSE_DOUBLE_CLICK_RADIUS: i32,
+    MOUSE_DOUBLE_CLICK_TIME: i32,
+    MOUSE_DEFAULT_SYSTEM_CURSOR: i32,
+    MOUSE_EMULATE_WARP_WITH_RELATIVE: bool,
+    MOUSE_FOCUS_CLICKTHROUGH: bool,
+    MOUSE_NORMAL_SPEED_SCALE: f32,
+    MOUSE_RELATIVE_MODE_CENTER: bool,
+    MOUSE_RELATIVE_SPEED_SCALE: f32,
+    MOUSE_RELATIVE_SYSTEM_SCALE: bool,
+    MOUSE_RELATIVE_WARP_MOTION: bool,
+    MOUSE_RELATIVE_CURSOR_VISIBLE: bool,
+    MOUSE_TOUCH_EVENTS: bool,
+    MUTE_CONSOLE_KEYBOARD: bool,
+    NO_SIGNAL_HANDLERS: bool,
+    OPENGL_LIBRARY: String,
+    EGL_LIBRARY: String,
+    OPENGL_ES_DRIVER: bool,
+    OPENVR_LIBRARY: String,
+    ORIENTATIONS: String,
+    POLL_SENTINEL: bool,
+    PREFERRED_LOCALES: String,
+    QUIT_ON_LAST_WINDOW_CLOSE: bool,
+    RENDER_DIRECT3D_THREADSAFE: bool,
+    RENDER_DIRECT3D11_DEBUG: bool,
+    RENDER_VULKAN_DEBUG: bool,
+    RENDER_GPU_DEBUG: bool,
+    RENDER_GPU_LOW_POWER: bool,
+    RENDER_DRIVER: String,
+    RENDER_LINE_METHOD: RenderLineMethod,
+    RENDER_METAL_PREFER_LOW_POWER_DEVICE: bool,
+    RENDER_VSYNC: bool,
+    RETURN_KEY_HIDES_IME: bool,
+    ROG_GAMEPAD_MICE: String,
+    ROG_GAMEPAD_MICE_EXCLUDED: String,
+    RPI_VIDEO_LAYER: i32,
+    SCREENSAVER_INHIBIT_ACTIVITY_NAME: String,
+    SHUTDOWN_DBUS_ON_QUIT: bool,
+    STORAGE_TITLE_DRIVER: String,
+    STORAGE_USER_DRIVER: String,
+    THREAD_FORCE_REALTIME_TIME_CRITICAL: bool,
+    THREAD_PRIORITY_POLICY: ThreadPriorityPolicy,
+    TIMER_RESOLUTION: i32,
+    TOUCH_MOUSE_EVENTS: bool,
+    TRACKPAD_IS_TOUCH_ONLY: bool,
+    TV_REMOTE_AS_JOYSTICK: bool,
+    VIDEO_ALLOW_SCREENSAVER: bool,
+    VIDEO_DISPLAY_PRIORITY: String,
+    VIDEO_DOUBLE_BUFFER: bool,
+    VIDEO_DRIVER: String,
+    VIDEO_DUMMY_SAVE_FRAMES: bool,
+    VIDEO_EGL_ALLOW_GETDISPLAY_FALLBACK: bool,
+    VIDEO_FORCE_EGL: bool,
+    VIDEO_MAC_FULLSCREEN_SPACES: bool,
+    VIDEO_MAC_FULLSCREEN_MENU_VISIBILITY: AutoToggle,
+    VIDEO_MINIMIZE_ON_FOCUS_LOSS: bool,
+    VIDEO_OFFSCREEN_SAVE_FRAMES: bool,
+    VIDEO_SYNC_WINDOW_OPERATIONS: bool,
+    VIDEO_WAYLAND_ALLOW_LIBDECOR: bool,
+    VIDEO_WAYLAND_MODE_EMULATION: bool,
+    VIDEO_WAYLAND_MODE_SCALING: WaylandModeScaling,
+    VIDEO_WAYLAND_PREFER_LIBDECOR: bool,
+    VIDEO_WAYLAND_SCALE_TO_DISPLAY: bool,
+    VIDEO_WIN_D3DCOMPILER: String,
+    VIDEO_X11_NET_WM_BYPASS_COMPOSITOR: bool,
+    VIDEO_X11_NET_WM_PING: bool,
+    VIDEO_X11_NODIRECTCOLOR: bool,
+    VIDEO_X11_SCALING_FACTOR: f32,
+    VIDEO_X11_VISUALID: String,
+    VIDEO_X11_WINDOW_VISUALID: String,
+    VIDEO_X11_XRANDR: bool,
+    VITA_ENABLE_BACK_TOUCH: bool,
+    VITA_ENABLE_FRONT_TOUCH: bool,
+    VITA_MODULE_PATH: String,
+    VITA_PVR_INIT: bool,
+    VITA_RESOLUTION: i32,
+    VITA_PVR_OPENGL: bool,
+    VITA_TOUCH_MOUSE_DEVICE: VitaTouchMouseDevice,
+    VULKAN_DISPLAY: String,
+    VULKAN_LIBRARY: String,
+    WAVE_FACT_CHUNK: WaveFactChunk,
+    WAVE_CHUNK_LIMIT: i32,
+    WAVE_RIFF_CHUNK_SIZE: WaveRiffChunkSize,
+    WAVE_TRUNCATION: WaveTruncation,
+    WINDOW_ACTIVATE_WHEN_RAISED: bool,
+    WINDOW_ACTIVATE_WHEN_SHOWN: bool,
+    WINDOW_ALLOW_TOPMOST: bool,
+    WINDOW_FRAME_USABLE_WHILE_CURSOR_HIDDEN: bool,
+    WINDOWS_CLOSE_ON_ALT_F4: bool,
+    WINDOWS_ENABLE_MENU_MNEMONICS: bool,
+    WINDOWS_ENABLE_MESSAGELOOP: bool,
+    WINDOWS_GAMEINPUT: bool,
+    WINDOWS_RAW_KEYBOARD: bool,
+    WINDOWS_FORCE_SEMAPHORE_KERNEL: bool,
+    WINDOWS_INTRESOURCE_ICON: i32,
+    WINDOWS_INTRESOURCE_ICON_SMALL: i32,
+    WINDOWS_USE_D3D9EX: bool,
+    WINDOWS_ERASE_BACKGROUND_MODE: EraseBackgroundMode,
+    X11_FORCE_OVERRIDE_REDIRECT: bool,
+    X11_WINDOW_TYPE: String,
+    X11_XCB_LIBRARY: String,
+    XINPUT_ENABLED: bool,
+    ASSERT: AssertBehavior,
+    PEN_MOUSE_EVENTS: bool,
+    PEN_TOUCH_EVENTS: bool,
+}
@@ -4,6 +4,7 @@ use libc::c_void;
 use std::ffi::CString;
 use std::io;
 use std::marker::PhantomData;
+use std::panic::{catch_unwind, AssertUnwindSafe};
 use std::path::Path;
 use std::ptr::NonNull;
 
@@ -96,6 +97,36 @@ impl<'a> IOStream<'a> {
         }
     }
 
+    /// Wraps any `Read + Write + Seek` implementor, e.g. a [`File`](std::fs::File) or a
+    /// [`Cursor`](io::Cursor), in an `IOStream`.
+    ///
+    /// Unlike [`IOStream::from_read`], SDL reads and writes through `stream` on demand. The
+    /// stream is owned by the `IOStream` and dropped when it's closed. I/O errors are reported
+    /// through [`IOStream::status`] and [`get_error`].
+    #[doc(alias = "SDL_OpenIO")]
+    pub fn from_interface<T>(stream: T) -> Result<IOStream<'a>, Error>
+    where
+        T: io::Read + io::Write + io::Seek + 'a,
+    {
+        let mut iface = sys::iostream::SDL_IOStreamInterface::new();
+        iface.size = Some(interface_size::<T>);
+        iface.seek = Some(interface_seek::<T>);
+        iface.read = Some(interface_read::<T>);
+        iface.write = Some(interface_write::<T>);
+        iface.flush = Some(interface_flush::<T>);
+        iface.close = Some(interface_close::<T>);
+
+        let userdata = Box::into_raw(Box::new(stream));
+        unsafe {
+            let raw = sys::iostream::SDL_OpenIO(&iface, userdata as *mut c_void);
+            if raw.is_null() {
+                // SDL only takes ownership of the stream if opening succeeds.
+                drop(Box::from_raw(userdata));
+            }
+            Self::from_ll_or_error(raw)
+        }
+    }
+
     /// Prepares a read-write memory buffer for use with `IOStream`.
     ///
     /// This method can only fail if the buffer size is zero.
@@ -138,6 +169,121 @@ impl<'a> IOStream<'a> {
     }
 }
 
+/// Sets the SDL error and the status for a failed operation of [`IOStream::from_interface`].
+unsafe fn interface_error(error: io::Error, status: *mut sys::iostream::SDL_IOStatus) {
+    *status = if error.kind() == io::ErrorKind::WouldBlock {
+        sys::iostream::SDL_IO_STATUS_NOT_READY
+    } else {
+        let _ = crate::set_error(&error.to_string());
+        sys::iostream::SDL_IO_STATUS_ERROR
+    };
+}
+
+/// Calls into the stream of [`IOStream::from_interface`]. A panic can't unwind into SDL, so it
+/// fails like an I/O error instead.
+fn interface_call<R>(call: impl FnOnce() -> io::Result<R>) -> io::Result<R> {
+    catch_unwind(AssertUnwindSafe(call))
+        .unwrap_or_else(|_| Err(io::Error::other("IOStream interface panicked")))
+}
+
+unsafe extern "C" fn interface_size<T: io::Seek>(userdata: *mut c_void) -> i64 {
+    let stream = &mut *(userdata as *mut T);
+    let size = interface_call(|| {
+        let position = stream.stream_position()?;
+        let size = stream.seek(io::SeekFrom::End(0))?;
+        stream.seek(io::SeekFrom::Start(position))?;
+        Ok(size)
+    });
+    match size {
+        Ok(size) => size as i64,
+        Err(error) => {
+            let _ = crate::set_error(&error.to_string());
+            -1
+        }
+    }
+}
+
+unsafe extern "C" fn interface_seek<T: io::Seek>(
+    userdata: *mut c_void,
+    offset: i64,
+    whence: sys::iostream::SDL_IOWhence,
+) -> i64 {
+    let pos = match whence {
+        sys::iostream::SDL_IO_SEEK_SET if offset >= 0 => io::SeekFrom::Start(offset as u64),
+        sys::iostream::SDL_IO_SEEK_CUR => io::SeekFrom::Current(offset),
+        sys::iostream::SDL_IO_SEEK_END => io::SeekFrom::End(offset),
+        _ => {
+            let _ = crate::set_error("Invalid seek position");
+            return -1;
+        }
+    };
+    match interface_call(|| (*(userdata as *mut T)).seek(pos)) {
+        Ok(position) => position as i64,
+        Err(error) => {
+            let _ = crate::set_error(&error.to_string());
+            -1
+        }
+    }
+}
+
+unsafe extern "C" fn interface_read<T: io::Read>(
+    userdata: *mut c_void,
+    ptr: *mut c_void,
+    size: usize,
+    status: *mut sys::iostream::SDL_IOStatus,
+) -> usize {
+    let buf = std::slice::from_raw_parts_mut(ptr as *mut u8, size);
+    loop {
+        match interface_call(|| (*(userdata as *mut T)).read(buf)) {
+            Ok(0) if size > 0 => *status = sys::iostream::SDL_IO_STATUS_EOF,
+            Ok(read) => return read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => interface_error(error, status),
+        }
+        return 0;
+    }
+}
+
+unsafe extern "C" fn interface_write<T: io::Write>(
+    userdata: *mut c_void,
+    ptr: *const c_void,
+    size: usize,
+    status: *mut sys::iostream::SDL_IOStatus,
+) -> usize {
+    let buf = std::slice::from_raw_parts(ptr as *const u8, size);
+    loop {
+        match interface_call(|| (*(userdata as *mut T)).write(buf)) {
+            Ok(written) => return written,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => interface_error(error, status),
+        }
+        return 0;
+    }
+}
+
+unsafe extern "C" fn interface_flush<T: io::Write>(
+    userdata: *mut c_void,
+    status: *mut sys::iostream::SDL_IOStatus,
+) -> bool {
+    match interface_call(|| (*(userdata as *mut T)).flush()) {
+        Ok(()) => true,
+        Err(error) => {
+            interface_error(error, status);
+            false
+        }
+    }
+}
+
+/// Always succeeds, since the stream is gone either way; a failed flush only sets the SDL error.
+unsafe extern "C" fn interface_close<T: io::Write>(userdata: *mut c_void) -> bool {
+    let mut stream = Box::from_raw(userdata as *mut T);
+    let result = interface_call(move || stream.flush());
+    if let Err(error) = result {
+        let _ = crate::set_error(&error.to_string());
+    }
+    true
+}
+
 /// See [`SDL_IOStatus`](sys::iostream::SDL_IOStatus)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum IOStatus {
@@ -181,7 +327,11 @@ impl io::Read for IOStream<'_> {
         let out_len = buf.len();
         let ret =
             unsafe { sys::iostream::SDL_ReadIO(self.raw(), buf.as_ptr() as *mut c_void, out_len) };
-        Ok(ret)
+        if ret == 0 && self.status() == IOStatus::Error {
+            Err(io::Error::other(get_error()))
+        } else {
+            Ok(ret)
+        }
     }
 }
 
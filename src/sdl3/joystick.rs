@@ -5,11 +5,14 @@ use crate::clear_error;
 use crate::common::{validate_int, IntegerOrSdlError};
 use crate::get_error;
 use crate::guid::Guid;
+#[cfg(feature = "hidapi")]
+use crate::sensor::SensorType;
 use crate::Error;
 use crate::JoystickSubsystem;
 use libc::{c_char, c_void};
-use std::ffi::CStr;
+use std::ffi::{CStr, CString};
 use std::fmt;
+use sys::joystick::{SDL_JoystickType, SDL_VirtualJoystickDesc, SDL_VirtualJoystickTouchpadDesc};
 use sys::power::{SDL_PowerState, SDL_POWERSTATE_UNKNOWN};
 use sys::stdinc::SDL_free;
 
@@ -91,6 +94,7 @@ impl fmt::Debug for PowerInfo {
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 #[repr(i32)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PowerLevel {
     Unknown = SDL_PowerState::UNKNOWN.0,
     Error = SDL_PowerState::ERROR.0,
@@ -466,6 +470,7 @@ impl Drop for Joystick {
 /// the same time... To simplify things I turn it into an enum which
 /// is how the SDL2 docs present it anyway (using macros).
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HatState {
     Centered = 0,
     Up = 0x01,
@@ -542,6 +547,373 @@ impl ConnectionState {
     }
 }
 
+/// The kind of device a joystick is.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum JoystickType {
+    Unknown,
+    Gamepad,
+    Wheel,
+    ArcadeStick,
+    FlightStick,
+    DancePad,
+    Guitar,
+    DrumKit,
+    ArcadePad,
+    Throttle,
+}
+
+impl JoystickType {
+    pub fn from_ll(raw: SDL_JoystickType) -> JoystickType {
+        match raw {
+            SDL_JoystickType::GAMEPAD => JoystickType::Gamepad,
+            SDL_JoystickType::WHEEL => JoystickType::Wheel,
+            SDL_JoystickType::ARCADE_STICK => JoystickType::ArcadeStick,
+            SDL_JoystickType::FLIGHT_STICK => JoystickType::FlightStick,
+            SDL_JoystickType::DANCE_PAD => JoystickType::DancePad,
+            SDL_JoystickType::GUITAR => JoystickType::Guitar,
+            SDL_JoystickType::DRUM_KIT => JoystickType::DrumKit,
+            SDL_JoystickType::ARCADE_PAD => JoystickType::ArcadePad,
+            SDL_JoystickType::THROTTLE => JoystickType::Throttle,
+            _ => JoystickType::Unknown,
+        }
+    }
+
+    pub fn to_ll(self) -> SDL_JoystickType {
+        match self {
+            JoystickType::Unknown => SDL_JoystickType::UNKNOWN,
+            JoystickType::Gamepad => SDL_JoystickType::GAMEPAD,
+            JoystickType::Wheel => SDL_JoystickType::WHEEL,
+            JoystickType::ArcadeStick => SDL_JoystickType::ARCADE_STICK,
+            JoystickType::FlightStick => SDL_JoystickType::FLIGHT_STICK,
+            JoystickType::DancePad => SDL_JoystickType::DANCE_PAD,
+            JoystickType::Guitar => SDL_JoystickType::GUITAR,
+            JoystickType::DrumKit => SDL_JoystickType::DRUM_KIT,
+            JoystickType::ArcadePad => SDL_JoystickType::ARCADE_PAD,
+            JoystickType::Throttle => SDL_JoystickType::THROTTLE,
+        }
+    }
+}
+
+/// Describes a virtual joystick, a joystick whose input is set by the application.
+///
+/// Virtual joysticks behave like real devices: they are listed by
+/// [`JoystickSubsystem::joysticks`], can be opened, and generate the usual events. This makes
+/// them useful for automated tests and for input injected from other sources.
+///
+/// # Example
+/// ```no_run
+/// use sdl3::gamepad::Axis;
+/// use sdl3::joystick::VirtualJoystickDesc;
+///
+/// let sdl_context = sdl3::init().unwrap();
+/// let joystick_subsystem = sdl_context.joystick().unwrap();
+///
+/// let mut gamepad = VirtualJoystickDesc::new("Test Pad")
+///     .gamepad()
+///     .attach(&joystick_subsystem)
+///     .unwrap();
+/// gamepad.set_gamepad_axis(Axis::LeftX, i16::MAX).unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct VirtualJoystickDesc {
+    name: String,
+    joystick_type: JoystickType,
+    vendor_id: u16,
+    product_id: u16,
+    axes: u16,
+    buttons: u16,
+    balls: u16,
+    hats: u16,
+    touchpads: Vec<u16>,
+    #[cfg(feature = "hidapi")]
+    sensors: Vec<(SensorType, f32)>,
+}
+
+impl VirtualJoystickDesc {
+    /// Initializes a new `VirtualJoystickDesc` for a joystick with no inputs.
+    pub fn new(name: &str) -> VirtualJoystickDesc {
+        VirtualJoystickDesc {
+            name: name.to_owned(),
+            joystick_type: JoystickType::Unknown,
+            vendor_id: 0,
+            product_id: 0,
+            axes: 0,
+            buttons: 0,
+            balls: 0,
+            hats: 0,
+            touchpads: Vec::new(),
+            #[cfg(feature = "hidapi")]
+            sensors: Vec::new(),
+        }
+    }
+
+    pub fn joystick_type(&mut self, joystick_type: JoystickType) -> &mut VirtualJoystickDesc {
+        self.joystick_type = joystick_type;
+        self
+    }
+
+    /// Makes the joystick a gamepad with every gamepad axis and button.
+    ///
+    /// Axis and button indices then match [`Axis`](crate::gamepad::Axis) and
+    /// [`Button`](crate::gamepad::Button), see [`VirtualJoystick::set_gamepad_axis`] and
+    /// [`VirtualJoystick::set_gamepad_button`].
+    pub fn gamepad(&mut self) -> &mut VirtualJoystickDesc {
+        self.joystick_type = JoystickType::Gamepad;
+        self.axes = sys::gamepad::SDL_GAMEPAD_AXIS_COUNT.0 as u16;
+        self.buttons = sys::gamepad::SDL_GAMEPAD_BUTTON_COUNT.0 as u16;
+        self
+    }
+
+    /// Sets the USB vendor id, which affects e.g. the detected
+    /// [`GamepadType`](crate::gamepad::GamepadType).
+    pub fn vendor_id(&mut self, vendor_id: u16) -> &mut VirtualJoystickDesc {
+        self.vendor_id = vendor_id;
+        self
+    }
+
+    /// Sets the USB product id.
+    pub fn product_id(&mut self, product_id: u16) -> &mut VirtualJoystickDesc {
+        self.product_id = product_id;
+        self
+    }
+
+    pub fn axes(&mut self, count: u16) -> &mut VirtualJoystickDesc {
+        self.axes = count;
+        self
+    }
+
+    pub fn buttons(&mut self, count: u16) -> &mut VirtualJoystickDesc {
+        self.buttons = count;
+        self
+    }
+
+    pub fn balls(&mut self, count: u16) -> &mut VirtualJoystickDesc {
+        self.balls = count;
+        self
+    }
+
+    pub fn hats(&mut self, count: u16) -> &mut VirtualJoystickDesc {
+        self.hats = count;
+        self
+    }
+
+    /// Adds a touchpad that tracks up to `fingers` simultaneous fingers.
+    pub fn touchpad(&mut self, fingers: u16) -> &mut VirtualJoystickDesc {
+        self.touchpads.push(fingers);
+        self
+    }
+
+    /// Adds a sensor that updates `rate` times per second, or 0.0 if unknown.
+    #[cfg(feature = "hidapi")]
+    pub fn sensor(&mut self, sensor_type: SensorType, rate: f32) -> &mut VirtualJoystickDesc {
+        self.sensors.push((sensor_type, rate));
+        self
+    }
+
+    /// Attaches the virtual joystick and opens it.
+    #[doc(alias = "SDL_AttachVirtualJoystick")]
+    pub fn attach(&self, subsystem: &JoystickSubsystem) -> Result<VirtualJoystick, Error> {
+        let name = CString::new(self.name.as_str()).map_err(|e| Error(e.to_string()))?;
+        let touchpads: Vec<SDL_VirtualJoystickTouchpadDesc> = self
+            .touchpads
+            .iter()
+            .map(|&nfingers| SDL_VirtualJoystickTouchpadDesc {
+                nfingers,
+                padding: [0; 3],
+            })
+            .collect();
+
+        let mut desc = SDL_VirtualJoystickDesc::new();
+        desc.r#type = self.joystick_type.to_ll().0 as u16;
+        desc.vendor_id = self.vendor_id;
+        desc.product_id = self.product_id;
+        desc.naxes = self.axes;
+        desc.nbuttons = self.buttons;
+        desc.nballs = self.balls;
+        desc.nhats = self.hats;
+        desc.ntouchpads = touchpads.len() as u16;
+        desc.touchpads = touchpads.as_ptr();
+        desc.name = name.as_ptr();
+
+        #[cfg(feature = "hidapi")]
+        let sensors: Vec<sys::joystick::SDL_VirtualJoystickSensorDesc> = self
+            .sensors
+            .iter()
+            .map(
+                |&(sensor_type, rate)| sys::joystick::SDL_VirtualJoystickSensorDesc {
+                    r#type: sensor_type.into(),
+                    rate,
+                },
+            )
+            .collect();
+        #[cfg(feature = "hidapi")]
+        {
+            desc.nsensors = sensors.len() as u16;
+            desc.sensors = sensors.as_ptr();
+        }
+
+        let id = unsafe { sys::joystick::SDL_AttachVirtualJoystick(&desc) };
+        if id == 0 {
+            return Err(get_error());
+        }
+
+        let raw = unsafe { sys::joystick::SDL_OpenJoystick(id) };
+        if raw.is_null() {
+            let error = get_error();
+            unsafe { sys::joystick::SDL_DetachVirtualJoystick(id) };
+            Err(error)
+        } else {
+            Ok(VirtualJoystick {
+                subsystem: subsystem.clone(),
+                id,
+                raw,
+            })
+        }
+    }
+}
+
+impl JoystickSubsystem {
+    /// Returns `true` if the joystick is a virtual joystick.
+    #[doc(alias = "SDL_IsJoystickVirtual")]
+    pub fn is_virtual(&self, joystick_id: JoystickId) -> bool {
+        unsafe { sys::joystick::SDL_IsJoystickVirtual(joystick_id) }
+    }
+}
+
+/// An attached virtual joystick, created with [`VirtualJoystickDesc::attach`].
+///
+/// New values are reported on the next joystick update, e.g. while pumping events. The
+/// joystick is detached when this is dropped.
+pub struct VirtualJoystick {
+    subsystem: JoystickSubsystem,
+    id: JoystickId,
+    raw: *mut sys::joystick::SDL_Joystick,
+}
+
+impl VirtualJoystick {
+    #[inline]
+    pub const fn subsystem(&self) -> &JoystickSubsystem {
+        &self.subsystem
+    }
+
+    /// Returns the instance id, e.g. for [`GamepadSubsystem::open`](crate::GamepadSubsystem::open).
+    #[inline]
+    pub const fn id(&self) -> JoystickId {
+        self.id
+    }
+
+    #[doc(alias = "SDL_SetJoystickVirtualAxis")]
+    pub fn set_axis(&mut self, axis: u32, value: i16) -> Result<(), IntegerOrSdlError> {
+        let axis = validate_int(axis, "axis")?;
+        let result = unsafe { sys::joystick::SDL_SetJoystickVirtualAxis(self.raw, axis, value) };
+        sdl_result(result)
+    }
+
+    #[doc(alias = "SDL_SetJoystickVirtualButton")]
+    pub fn set_button(&mut self, button: u32, down: bool) -> Result<(), IntegerOrSdlError> {
+        let button = validate_int(button, "button")?;
+        let result = unsafe { sys::joystick::SDL_SetJoystickVirtualButton(self.raw, button, down) };
+        sdl_result(result)
+    }
+
+    /// Sets the position of a gamepad axis, for joysticks described with
+    /// [`VirtualJoystickDesc::gamepad`].
+    pub fn set_gamepad_axis(
+        &mut self,
+        axis: crate::gamepad::Axis,
+        value: i16,
+    ) -> Result<(), IntegerOrSdlError> {
+        self.set_axis(axis.to_ll().0 as u32, value)
+    }
+
+    /// Sets the state of a gamepad button, for joysticks described with
+    /// [`VirtualJoystickDesc::gamepad`].
+    pub fn set_gamepad_button(
+        &mut self,
+        button: crate::gamepad::Button,
+        down: bool,
+    ) -> Result<(), IntegerOrSdlError> {
+        self.set_button(button.to_ll().0 as u32, down)
+    }
+
+    #[doc(alias = "SDL_SetJoystickVirtualBall")]
+    pub fn set_ball(&mut self, ball: u32, xrel: i16, yrel: i16) -> Result<(), IntegerOrSdlError> {
+        let ball = validate_int(ball, "ball")?;
+        let result =
+            unsafe { sys::joystick::SDL_SetJoystickVirtualBall(self.raw, ball, xrel, yrel) };
+        sdl_result(result)
+    }
+
+    #[doc(alias = "SDL_SetJoystickVirtualHat")]
+    pub fn set_hat(&mut self, hat: u32, state: HatState) -> Result<(), IntegerOrSdlError> {
+        let hat = validate_int(hat, "hat")?;
+        let result =
+            unsafe { sys::joystick::SDL_SetJoystickVirtualHat(self.raw, hat, state.to_raw()) };
+        sdl_result(result)
+    }
+
+    /// Sets the state of a finger on a touchpad, `x` and `y` range from 0.0 to 1.0 starting at
+    /// the top left.
+    #[doc(alias = "SDL_SetJoystickVirtualTouchpad")]
+    pub fn set_touchpad(
+        &mut self,
+        touchpad: u32,
+        finger: u32,
+        down: bool,
+        x: f32,
+        y: f32,
+        pressure: f32,
+    ) -> Result<(), IntegerOrSdlError> {
+        let touchpad = validate_int(touchpad, "touchpad")?;
+        let finger = validate_int(finger, "finger")?;
+        let result = unsafe {
+            sys::joystick::SDL_SetJoystickVirtualTouchpad(
+                self.raw, touchpad, finger, down, x, y, pressure,
+            )
+        };
+        sdl_result(result)
+    }
+
+    /// Sends new sensor data, `timestamp` is the sensor's own timestamp in nanoseconds.
+    #[cfg(feature = "hidapi")]
+    #[doc(alias = "SDL_SendJoystickVirtualSensorData")]
+    pub fn send_sensor_data(
+        &mut self,
+        sensor_type: SensorType,
+        timestamp: u64,
+        data: &[f32],
+    ) -> Result<(), IntegerOrSdlError> {
+        let result = unsafe {
+            sys::joystick::SDL_SendJoystickVirtualSensorData(
+                self.raw,
+                sensor_type.into(),
+                timestamp,
+                data.as_ptr(),
+                data.len() as libc::c_int,
+            )
+        };
+        sdl_result(result)
+    }
+}
+
+impl Drop for VirtualJoystick {
+    #[doc(alias = "SDL_DetachVirtualJoystick")]
+    fn drop(&mut self) {
+        unsafe {
+            sys::joystick::SDL_CloseJoystick(self.raw);
+            sys::joystick::SDL_DetachVirtualJoystick(self.id);
+        }
+    }
+}
+
+fn sdl_result(result: bool) -> Result<(), IntegerOrSdlError> {
+    if result {
+        Ok(())
+    } else {
+        Err(IntegerOrSdlError::SdlError(get_error()))
+    }
+}
+
 /// Convert C string `c_str` to a String. Return an empty string if
 /// `c_str` is NULL.
 fn c_str_to_string(c_str: *const c_char) -> String {
@@ -590,3 +590,40 @@ impl Keycode {
         }
     }
 }
+
+/// Keycodes are serialized by their SDL name, such as `"Left Shift"` or `"A"`, in human readable formats
+/// unless the name is shared with another key, and by their raw value otherwise.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Keycode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() {
+            return serializer.serialize_i32(*self as i32);
+        }
+        let name = &self.name();
+        // Some names are shared, e.g. `Return2` is also called "Return", so a name is only used
+        // if it maps back to the same keycode.
+        if (name.is_empty() && *self == Keycode::Unknown) || Keycode::from_name(name) == Some(*self)
+        {
+            serializer.serialize_str(name)
+        } else {
+            serializer.serialize_i32(*self as i32)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Keycode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Keycode, D::Error> {
+        let visitor = super::KeyNameVisitor {
+            expecting: "a key name",
+            unknown: Keycode::Unknown,
+            from_name: Keycode::from_name,
+            from_i32: Keycode::from_i32,
+        };
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(visitor)
+        } else {
+            deserializer.deserialize_i32(visitor)
+        }
+    }
+}
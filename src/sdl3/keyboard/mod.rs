@@ -1,9 +1,17 @@
+use crate::get_error;
 use crate::rect::Rect;
 use crate::video::Window;
+use crate::Error;
 use crate::EventPump;
 
 use crate::sys;
+use libc::c_void;
+use std::ffi::CStr;
 use std::fmt;
+use sys::properties::{
+    SDL_CreateProperties, SDL_DestroyProperties, SDL_SetBooleanProperty, SDL_SetNumberProperty,
+};
+use sys::stdinc::SDL_free;
 use sys::video::SDL_GetWindowID;
 
 mod keycode;
@@ -11,8 +19,13 @@ mod scancode;
 pub use self::keycode::Keycode;
 pub use self::scancode::Scancode;
 
+/// The id of a keyboard, only valid while it is connected.
+pub type KeyboardId = sys::keyboard::SDL_KeyboardID;
+
 bitflags! {
     #[derive(Debug, Copy, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(transparent))]
     pub struct Mod: u16 {
         const NOMOD = 0x0000;
         const LSHIFTMOD = 0x0001;
@@ -36,6 +49,47 @@ impl fmt::Display for Mod {
     }
 }
 
+/// Deserializes a [`Keycode`] or [`Scancode`] from its SDL name, or from its raw value for the
+/// few keys that don't have a name.
+#[cfg(feature = "serde")]
+struct KeyNameVisitor<T> {
+    expecting: &'static str,
+    unknown: T,
+    from_name: fn(&str) -> Option<T>,
+    from_i32: fn(i32) -> Option<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> serde::de::Visitor<'_> for KeyNameVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E: serde::de::Error>(self, name: &str) -> Result<T, E> {
+        if name.is_empty() {
+            return Ok(self.unknown);
+        }
+        (self.from_name)(name)
+            .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Str(name), &self))
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, value: i64) -> Result<T, E> {
+        i32::try_from(value)
+            .ok()
+            .and_then(self.from_i32)
+            .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Signed(value), &self))
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, value: u64) -> Result<T, E> {
+        i32::try_from(value)
+            .ok()
+            .and_then(self.from_i32)
+            .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Unsigned(value), &self))
+    }
+}
+
 pub struct KeyboardState<'a> {
     keyboard_state: &'a [bool],
 }
@@ -202,6 +256,131 @@ impl KeyboardUtil {
             sys::keyboard::SDL_SetModState(flags.bits());
         }
     }
+
+    /// Returns `true` if a keyboard is connected.
+    #[doc(alias = "SDL_HasKeyboard")]
+    pub fn has_keyboard(&self) -> bool {
+        unsafe { sys::keyboard::SDL_HasKeyboard() }
+    }
+
+    /// Get the ids of the currently connected keyboards.
+    ///
+    /// This includes every device with keyboard functionality, such as some mice or power
+    /// buttons, so wait for input from a keyboard before treating it as in use. Key events
+    /// carry the id of the keyboard they come from in `which`.
+    #[doc(alias = "SDL_GetKeyboards")]
+    pub fn keyboards(&self) -> Result<Vec<KeyboardId>, Error> {
+        let mut count = 0;
+        unsafe {
+            let keyboard_ids = sys::keyboard::SDL_GetKeyboards(&mut count);
+            if keyboard_ids.is_null() {
+                Err(get_error())
+            } else {
+                let ids = std::slice::from_raw_parts(keyboard_ids, count as usize).to_vec();
+                SDL_free(keyboard_ids as *mut c_void);
+                Ok(ids)
+            }
+        }
+    }
+
+    /// Get the name of a keyboard.
+    #[doc(alias = "SDL_GetKeyboardNameForID")]
+    pub fn name_for_id(&self, keyboard_id: KeyboardId) -> Result<String, Error> {
+        unsafe {
+            let name = sys::keyboard::SDL_GetKeyboardNameForID(keyboard_id);
+            if name.is_null() {
+                Err(get_error())
+            } else {
+                Ok(CStr::from_ptr(name).to_string_lossy().into_owned())
+            }
+        }
+    }
+}
+
+/// The kind of text being entered, which e.g. decides the layout of an on-screen keyboard.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum TextInputType {
+    Text,
+    /// A person's name
+    Name,
+    Email,
+    Username,
+    /// A password that is hidden while typing
+    PasswordHidden,
+    /// A password that is shown while typing
+    PasswordVisible,
+    Number,
+    /// A PIN that is hidden while typing
+    NumberPasswordHidden,
+    /// A PIN that is shown while typing
+    NumberPasswordVisible,
+}
+
+impl TextInputType {
+    pub fn to_ll(self) -> sys::keyboard::SDL_TextInputType {
+        use sys::keyboard::SDL_TextInputType;
+        match self {
+            TextInputType::Text => SDL_TextInputType::TEXT,
+            TextInputType::Name => SDL_TextInputType::TEXT_NAME,
+            TextInputType::Email => SDL_TextInputType::TEXT_EMAIL,
+            TextInputType::Username => SDL_TextInputType::TEXT_USERNAME,
+            TextInputType::PasswordHidden => SDL_TextInputType::TEXT_PASSWORD_HIDDEN,
+            TextInputType::PasswordVisible => SDL_TextInputType::TEXT_PASSWORD_VISIBLE,
+            TextInputType::Number => SDL_TextInputType::NUMBER,
+            TextInputType::NumberPasswordHidden => SDL_TextInputType::NUMBER_PASSWORD_HIDDEN,
+            TextInputType::NumberPasswordVisible => SDL_TextInputType::NUMBER_PASSWORD_VISIBLE,
+        }
+    }
+}
+
+/// Auto-capitalization of text input.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Capitalization {
+    None,
+    /// The first letter of sentences
+    Sentences,
+    /// The first letter of words
+    Words,
+    /// All letters
+    Letters,
+}
+
+impl Capitalization {
+    pub fn to_ll(self) -> sys::keyboard::SDL_Capitalization {
+        use sys::keyboard::SDL_Capitalization;
+        match self {
+            Capitalization::None => SDL_Capitalization::NONE,
+            Capitalization::Sentences => SDL_Capitalization::SENTENCES,
+            Capitalization::Words => SDL_Capitalization::WORDS,
+            Capitalization::Letters => SDL_Capitalization::LETTERS,
+        }
+    }
+}
+
+/// Options for [`TextInputUtil::start_with_properties`].
+///
+/// These are hints for IMEs and on-screen keyboards, platforms may ignore some of them.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct TextInputProperties {
+    pub input_type: TextInputType,
+    /// Defaults to [`Capitalization::Sentences`] for [`TextInputType::Text`],
+    /// [`Capitalization::Words`] for [`TextInputType::Name`], and [`Capitalization::None`]
+    /// otherwise.
+    pub capitalization: Option<Capitalization>,
+    pub autocorrect: bool,
+    /// Whether the return key inserts a line break instead of finishing the input.
+    pub multiline: bool,
+}
+
+impl Default for TextInputProperties {
+    fn default() -> TextInputProperties {
+        TextInputProperties {
+            input_type: TextInputType::Text,
+            capitalization: None,
+            autocorrect: true,
+            multiline: false,
+        }
+    }
 }
 
 /// Text input utility functions. Access with `VideoSubsystem::text_input()`.
@@ -228,6 +407,71 @@ impl TextInputUtil {
         }
     }
 
+    /// Starts accepting text input like [`start`](Self::start), and tells IMEs and on-screen
+    /// keyboards what kind of text to expect.
+    ///
+    /// ```no_run
+    /// use sdl3::keyboard::{TextInputProperties, TextInputType};
+    ///
+    /// # let sdl_context = sdl3::init().unwrap();
+    /// # let video_subsystem = sdl_context.video().unwrap();
+    /// # let window = video_subsystem.window("Example", 800, 600).build().unwrap();
+    /// video_subsystem
+    ///     .text_input()
+    ///     .start_with_properties(
+    ///         &window,
+    ///         &TextInputProperties {
+    ///             input_type: TextInputType::Email,
+    ///             autocorrect: false,
+    ///             ..Default::default()
+    ///         },
+    ///     )
+    ///     .unwrap();
+    /// ```
+    #[doc(alias = "SDL_StartTextInputWithProperties")]
+    pub fn start_with_properties(
+        &self,
+        window: &Window,
+        properties: &TextInputProperties,
+    ) -> Result<(), Error> {
+        unsafe {
+            let props = SDL_CreateProperties();
+            if props == 0 {
+                return Err(get_error());
+            }
+            SDL_SetNumberProperty(
+                props,
+                sys::keyboard::SDL_PROP_TEXTINPUT_TYPE_NUMBER,
+                properties.input_type.to_ll().0.into(),
+            );
+            if let Some(capitalization) = properties.capitalization {
+                SDL_SetNumberProperty(
+                    props,
+                    sys::keyboard::SDL_PROP_TEXTINPUT_CAPITALIZATION_NUMBER,
+                    capitalization.to_ll().0.into(),
+                );
+            }
+            SDL_SetBooleanProperty(
+                props,
+                sys::keyboard::SDL_PROP_TEXTINPUT_AUTOCORRECT_BOOLEAN,
+                properties.autocorrect,
+            );
+            SDL_SetBooleanProperty(
+                props,
+                sys::keyboard::SDL_PROP_TEXTINPUT_MULTILINE_BOOLEAN,
+                properties.multiline,
+            );
+
+            let result = sys::keyboard::SDL_StartTextInputWithProperties(window.raw(), props);
+            SDL_DestroyProperties(props);
+            if result {
+                Ok(())
+            } else {
+                Err(get_error())
+            }
+        }
+    }
+
     #[doc(alias = "SDL_TextInputActive")]
     pub fn is_active(&self, window: &Window) -> bool {
         unsafe { sys::keyboard::SDL_TextInputActive(window.raw()) }
@@ -240,8 +484,18 @@ impl TextInputUtil {
         }
     }
 
+    /// Dismisses the composition window or IME without committing the text.
+    #[doc(alias = "SDL_ClearComposition")]
+    pub fn clear_composition(&self, window: &Window) -> Result<(), Error> {
+        if unsafe { sys::keyboard::SDL_ClearComposition(window.raw()) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
     #[doc(alias = "SDL_SetTextInputArea")]
-    pub fn set_rect(&self, window: Window, rect: Rect, cursor: i32) {
+    pub fn set_rect(&self, window: &Window, rect: Rect, cursor: i32) {
         unsafe {
             sys::keyboard::SDL_SetTextInputArea(
                 window.raw(),
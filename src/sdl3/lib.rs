@@ -6,7 +6,7 @@
 //! use sdl3::pixels::Color;
 //! use sdl3::event::Event;
 //! use sdl3::keyboard::Keycode;
-//! use std::time::Duration;
+//! use sdl3::timer::FrameLimiter;
 //!
 //! pub fn main() {
 //!     let sdl_context = sdl3::init().unwrap();
@@ -23,6 +23,7 @@
 //!     canvas.clear();
 //!     canvas.present();
 //!     let mut event_pump = sdl_context.event_pump().unwrap();
+//!     let mut frame_limiter = FrameLimiter::new(60);
 //!     let mut i = 0;
 //!     'running: loop {
 //!         i = (i + 1) % 255;
@@ -40,7 +41,7 @@
 //!         // The rest of the game loop goes here...
 //!
 //!         canvas.present();
-//!         ::std::thread::sleep(Duration::new(0, 1_000_000_000u32 / 60));
+//!         frame_limiter.wait();
 //!     }
 //! }
 //! ```
@@ -83,6 +84,10 @@
 //! | `hidapi`            | Use hidapi support in SDL                                              | TODO                  |
 //! | `test-mode`         | Allows SDL to be initialised from a thread that is not the main thread | Implemented           |
 //! | `raw-window-handle` | Enables integrations with the [`wgpu`] crate                           | Implemented           |
+//! | `async`             | Async event stream implementing `futures_core::Stream`                 | Implemented           |
+//! | `serde`             | `Serialize`/`Deserialize` for input types, and event recording/replay  | Implemented           |
+//! | `log`               | Forward SDL log output to the `log` crate, and log through SDL         | Implemented           |
+//! | `tracing`           | Forward SDL log output to the `tracing` crate                          | Implemented           |
 //!
 //! [`wgpu`]: https://docs.rs/wgpu/latest/wgpu/
 
@@ -111,7 +116,11 @@ pub mod clipboard;
 pub mod cpuinfo;
 #[macro_use]
 mod macros;
+pub mod app;
+pub mod assert;
+pub mod async_io;
 pub mod audio;
+pub mod camera;
 pub mod dialog;
 pub mod event;
 pub mod filesystem;
@@ -122,17 +131,25 @@ pub mod hint;
 pub mod iostream;
 pub mod joystick;
 pub mod keyboard;
+pub mod locale;
 pub mod log;
 pub mod messagebox;
 pub mod mouse;
+pub mod pen;
 pub mod pixels;
+pub mod power;
+pub mod process;
 pub mod properties;
 pub mod rect;
 pub mod render;
+#[cfg(feature = "serde")]
+pub mod replay;
 mod sdl;
 #[cfg(feature = "hidapi")]
 pub mod sensor;
+pub mod storage;
 pub mod surface;
+pub mod time;
 pub mod timer;
 pub mod touch;
 pub mod url;
@@ -0,0 +1,63 @@
+//! Locale information
+//!
+//! Queries the user's preferred languages, most preferred first. These can change while the app
+//! is running, which is reported with [`Event::LocaleChanged`](crate::event::Event::LocaleChanged).
+
+use crate::sys;
+use libc::{c_int, c_void};
+use std::ffi::CStr;
+use std::fmt;
+
+/// A language and optionally a country, e.g. `en_US` or `de`.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Locale {
+    /// An ISO-639 language specifier, e.g. "en" for English or "de" for German.
+    pub language: String,
+    /// An ISO-3166 country code, e.g. "US" for the United States or "CA" for Canada.
+    pub country: Option<String>,
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.country {
+            Some(country) => write!(f, "{}_{}", self.language, country),
+            None => write!(f, "{}", self.language),
+        }
+    }
+}
+
+/// Returns the user's preferred locales, most preferred first.
+///
+/// The list is empty if the preferences can't be determined. It can change while the app is
+/// running, so don't cache it past an [`Event::LocaleChanged`](crate::event::Event::LocaleChanged).
+#[doc(alias = "SDL_GetPreferredLocales")]
+pub fn preferred_locales() -> Vec<Locale> {
+    let mut count: c_int = 0;
+    unsafe {
+        let locales = sys::locale::SDL_GetPreferredLocales(&mut count);
+        if locales.is_null() {
+            return Vec::new();
+        }
+        let result = (0..count as usize)
+            .map(|i| {
+                let locale = &**locales.add(i);
+                Locale {
+                    language: CStr::from_ptr(locale.language)
+                        .to_string_lossy()
+                        .into_owned(),
+                    country: if locale.country.is_null() {
+                        None
+                    } else {
+                        Some(
+                            CStr::from_ptr(locale.country)
+                                .to_string_lossy()
+                                .into_owned(),
+                        )
+                    },
+                }
+            })
+            .collect();
+        sys::stdinc::SDL_free(locales as *mut c_void);
+        result
+    }
+}
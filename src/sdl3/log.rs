@@ -1,7 +1,20 @@
+//! Logging through SDL
+//!
+//! Messages are logged with a [`Category`] and a [`Priority`], and SDL drops those below the
+//! priority set for their category. The rest goes to an output function, which can be replaced
+//! with [`set_output_function`] or [`set_output_closure`].
+//!
+//! With the `log` or `tracing` feature, SDL's output can be forwarded to those crates with
+//! [`forward_to_log`] or [`forward_to_tracing`]. The category becomes the target, e.g.
+//! `sdl3::video`, see [`Category::target`]. The `log` feature also provides a [`Logger`] that
+//! writes the messages of the `log` crate through SDL.
+
 use crate::sys;
-use std::ffi::{CStr, CString};
+use crate::util::ClosureSlot;
+use std::ffi::{c_void, CStr, CString};
 use std::mem::transmute;
 use std::ptr::null_mut;
+use std::sync::Arc;
 use sys::log::*;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -38,6 +51,38 @@ impl Category {
         }
     }
 
+    /// The target that forwarded messages of this category are logged with, e.g.
+    /// `"sdl3::video"`.
+    pub fn target(self) -> &'static str {
+        macro_rules! target {
+            ($target:literal,) => {
+                $target
+            };
+        }
+        with_category_target!(self, target!())
+    }
+
+    #[cfg(feature = "log")]
+    fn from_target(target: &str) -> Category {
+        const CATEGORIES: [Category; 11] = [
+            Category::Application,
+            Category::Error,
+            Category::Assert,
+            Category::System,
+            Category::Audio,
+            Category::Video,
+            Category::Render,
+            Category::Input,
+            Category::Test,
+            Category::Gpu,
+            Category::Custom,
+        ];
+        CATEGORIES
+            .into_iter()
+            .find(|category| category.target() == target)
+            .unwrap_or(Category::Application)
+    }
+
     fn to_ll(&self) -> u32 {
         match *self {
             Category::Application => SDL_LOG_CATEGORY_APPLICATION.0 as u32,
@@ -57,8 +102,31 @@ impl Category {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+/// Expands `$callback!` with the target of `$category` as a literal, which the `tracing`
+/// macros require.
+macro_rules! with_category_target {
+    ($category:expr, $callback:ident!($($args:tt)*)) => {
+        match $category {
+            Category::Application => $callback!("sdl3::app", $($args)*),
+            Category::Error => $callback!("sdl3::error", $($args)*),
+            Category::Assert => $callback!("sdl3::assert", $($args)*),
+            Category::System => $callback!("sdl3::system", $($args)*),
+            Category::Audio => $callback!("sdl3::audio", $($args)*),
+            Category::Video => $callback!("sdl3::video", $($args)*),
+            Category::Render => $callback!("sdl3::render", $($args)*),
+            Category::Input => $callback!("sdl3::input", $($args)*),
+            Category::Test => $callback!("sdl3::test", $($args)*),
+            Category::Gpu => $callback!("sdl3::gpu", $($args)*),
+            Category::Custom => $callback!("sdl3::custom", $($args)*),
+            Category::Unknown => $callback!("sdl3::unknown", $($args)*),
+        }
+    };
+}
+use with_category_target;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Priority {
+    Trace,
     Verbose,
     Debug,
     Info,
@@ -70,6 +138,7 @@ pub enum Priority {
 impl Priority {
     fn from_ll(value: SDL_LogPriority) -> Priority {
         match value {
+            SDL_LOG_PRIORITY_TRACE => Priority::Trace,
             SDL_LOG_PRIORITY_VERBOSE => Priority::Verbose,
             SDL_LOG_PRIORITY_DEBUG => Priority::Debug,
             SDL_LOG_PRIORITY_INFO => Priority::Info,
@@ -81,6 +150,7 @@ impl Priority {
 
     fn to_ll(&self) -> u32 {
         match *self {
+            Priority::Trace => SDL_LOG_PRIORITY_TRACE.0 as u32,
             Priority::Verbose => SDL_LOG_PRIORITY_VERBOSE.0 as u32,
             Priority::Debug => SDL_LOG_PRIORITY_DEBUG.0 as u32,
             Priority::Info => SDL_LOG_PRIORITY_INFO.0 as u32,
@@ -117,6 +187,263 @@ pub fn set_output_function(callback: fn(Priority, Category, &str)) {
     };
 }
 
+type OutputClosure = dyn Fn(Priority, Category, &str) + Send + Sync;
+
+static OUTPUT_CLOSURE: ClosureSlot<OutputClosure> = ClosureSlot::new();
+
+/// Passed as the userdata of [`closure_log_fn`] to recognize it.
+static CLOSURE_USERDATA: u8 = 0;
+
+fn closure_userdata() -> *mut c_void {
+    &CLOSURE_USERDATA as *const u8 as *mut c_void
+}
+
+unsafe extern "C" fn closure_log_fn(
+    _userdata: *mut c_void,
+    category: libc::c_int,
+    priority: SDL_LogPriority,
+    message: *const libc::c_char,
+) {
+    if let Some(closure) = OUTPUT_CLOSURE.get() {
+        let message = CStr::from_ptr(message).to_string_lossy();
+        closure(
+            Priority::from_ll(priority),
+            Category::from_ll(category as u32),
+            &message,
+        );
+    }
+}
+
+/// The output function that was replaced by [`set_output_closure`].
+///
+/// SDL keeps using the new output function until [`restore`](PreviousOutput::restore) is
+/// called. Until then the previous one can still be called, e.g. to keep SDL's default output
+/// while also capturing messages.
+pub struct PreviousOutput {
+    callback: SDL_LogOutputFunction,
+    userdata: *mut c_void,
+    closure: Option<Arc<OutputClosure>>,
+}
+
+// The userdata is either null or owned by whoever set the output function, SDL passes it to
+// the function from any thread as well.
+unsafe impl Send for PreviousOutput {}
+unsafe impl Sync for PreviousOutput {}
+
+impl PreviousOutput {
+    /// Passes a message to the previous output function.
+    pub fn call(&self, category: Category, priority: Priority, message: &str) {
+        if self.userdata == closure_userdata() {
+            if let Some(closure) = &self.closure {
+                closure(priority, category, message);
+            }
+        } else if let Some(callback) = self.callback {
+            let message = CString::new(message.replace('\0', "")).unwrap();
+            unsafe {
+                callback(
+                    self.userdata,
+                    category.to_ll() as i32,
+                    SDL_LogPriority(priority.to_ll() as i32),
+                    message.as_ptr(),
+                )
+            }
+        }
+    }
+
+    /// Makes the previous output function the current one again.
+    #[doc(alias = "SDL_SetLogOutputFunction")]
+    pub fn restore(self) {
+        OUTPUT_CLOSURE.replace(self.closure);
+        unsafe { SDL_SetLogOutputFunction(self.callback, self.userdata) }
+    }
+}
+
+/// Sets a closure as the output function, and returns the one it replaces so that it can be
+/// restored.
+///
+/// Unlike [`set_output_function`], the closure can capture state, e.g. a channel to send the
+/// messages over. It may be called from any thread.
+///
+/// # Example
+/// ```rust,no_run
+/// use sdl3::log::{self, Category, Priority};
+/// use std::sync::mpsc;
+///
+/// let (sender, receiver) = mpsc::channel();
+/// let sender = std::sync::Mutex::new(sender);
+/// let previous = log::set_output_closure(move |priority, category, message| {
+///     let _ = sender.lock().unwrap().send((priority, category, message.to_owned()));
+/// });
+///
+/// log::log_message(Category::Application, Priority::Info, "Hello");
+/// assert_eq!(receiver.recv().unwrap().2, "Hello");
+/// previous.restore();
+/// ```
+#[doc(alias = "SDL_GetLogOutputFunction")]
+#[doc(alias = "SDL_SetLogOutputFunction")]
+pub fn set_output_closure<F>(callback: F) -> PreviousOutput
+where
+    F: Fn(Priority, Category, &str) + Send + Sync + 'static,
+{
+    let mut previous = PreviousOutput {
+        callback: None,
+        userdata: null_mut(),
+        closure: None,
+    };
+    unsafe { SDL_GetLogOutputFunction(&mut previous.callback, &mut previous.userdata) };
+    previous.closure = OUTPUT_CLOSURE.replace(Some(Arc::new(callback)));
+    unsafe { SDL_SetLogOutputFunction(Some(closure_log_fn), closure_userdata()) };
+    previous
+}
+
+#[cfg(feature = "log")]
+thread_local! {
+    /// Set while a message of SDL is passed to the `log` crate, so that [`Logger`] doesn't
+    /// send it back to SDL.
+    static FORWARDING_TO_LOG: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+#[cfg(feature = "log")]
+unsafe extern "C" fn forward_to_log_fn(
+    _userdata: *mut c_void,
+    category: libc::c_int,
+    priority: SDL_LogPriority,
+    message: *const libc::c_char,
+) {
+    let category = Category::from_ll(category as u32);
+    let level = match Priority::from_ll(priority) {
+        Priority::Trace | Priority::Verbose => ::log::Level::Trace,
+        Priority::Debug => ::log::Level::Debug,
+        Priority::Info => ::log::Level::Info,
+        Priority::Warn => ::log::Level::Warn,
+        Priority::Error | Priority::Critical => ::log::Level::Error,
+    };
+    let message = CStr::from_ptr(message).to_string_lossy();
+    FORWARDING_TO_LOG.with(|forwarding| {
+        forwarding.set(true);
+        ::log::log!(target: category.target(), level, "{}", message);
+        forwarding.set(false);
+    });
+}
+
+/// Sets an output function that forwards SDL's messages to the `log` crate.
+///
+/// The category becomes the target, see [`Category::target`]. SDL still drops messages below
+/// the priority of their category first, use [`set_log_priorities`] to leave filtering to the
+/// logger.
+#[cfg(feature = "log")]
+#[doc(alias = "SDL_SetLogOutputFunction")]
+pub fn forward_to_log() {
+    unsafe { SDL_SetLogOutputFunction(Some(forward_to_log_fn), null_mut()) }
+}
+
+#[cfg(feature = "tracing")]
+unsafe extern "C" fn forward_to_tracing_fn(
+    _userdata: *mut c_void,
+    category: libc::c_int,
+    priority: SDL_LogPriority,
+    message: *const libc::c_char,
+) {
+    macro_rules! event {
+        ($target:literal, $priority:expr, $message:expr) => {
+            match $priority {
+                Priority::Trace | Priority::Verbose => {
+                    tracing::trace!(target: $target, "{}", $message)
+                }
+                Priority::Debug => tracing::debug!(target: $target, "{}", $message),
+                Priority::Info => tracing::info!(target: $target, "{}", $message),
+                Priority::Warn => tracing::warn!(target: $target, "{}", $message),
+                Priority::Error | Priority::Critical => {
+                    tracing::error!(target: $target, "{}", $message)
+                }
+            }
+        };
+    }
+
+    let category = Category::from_ll(category as u32);
+    let priority = Priority::from_ll(priority);
+    let message = CStr::from_ptr(message).to_string_lossy();
+    with_category_target!(category, event!(priority, message));
+}
+
+/// Sets an output function that forwards SDL's messages to the `tracing` crate as events.
+///
+/// The category becomes the target, see [`Category::target`]. SDL still drops messages below
+/// the priority of their category first, use [`set_log_priorities`] to leave filtering to the
+/// subscriber.
+#[cfg(feature = "tracing")]
+#[doc(alias = "SDL_SetLogOutputFunction")]
+pub fn forward_to_tracing() {
+    unsafe { SDL_SetLogOutputFunction(Some(forward_to_tracing_fn), null_mut()) }
+}
+
+/// A logger for the `log` crate that writes through `SDL_LogMessage`.
+///
+/// Targets named like [`Category::target`] are logged with that category, all others as
+/// [`Category::Application`]. Messages are dropped if SDL would drop them, see
+/// [`set_log_priority`].
+///
+/// # Example
+/// ```rust,no_run
+/// sdl3::log::Logger::init(log::LevelFilter::Info).unwrap();
+/// log::info!("Hello from SDL");
+/// ```
+#[cfg(feature = "log")]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Logger;
+
+#[cfg(feature = "log")]
+impl Logger {
+    /// Sets a `Logger` as the logger of the `log` crate.
+    pub fn init(max_level: ::log::LevelFilter) -> Result<(), ::log::SetLoggerError> {
+        static LOGGER: Logger = Logger;
+        ::log::set_logger(&LOGGER)?;
+        ::log::set_max_level(max_level);
+        Ok(())
+    }
+
+    fn priority(level: ::log::Level) -> Priority {
+        match level {
+            ::log::Level::Trace => Priority::Trace,
+            ::log::Level::Debug => Priority::Debug,
+            ::log::Level::Info => Priority::Info,
+            ::log::Level::Warn => Priority::Warn,
+            ::log::Level::Error => Priority::Error,
+        }
+    }
+}
+
+#[cfg(feature = "log")]
+impl ::log::Log for Logger {
+    fn enabled(&self, metadata: &::log::Metadata) -> bool {
+        let category = Category::from_target(metadata.target());
+        Logger::priority(metadata.level()) >= get_log_priority(category)
+    }
+
+    #[doc(alias = "SDL_LogMessage")]
+    fn log(&self, record: &::log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let category = Category::from_target(record.target()).to_ll() as i32;
+        let priority = SDL_LogPriority(Logger::priority(record.level()).to_ll() as i32);
+        let message = CString::new(record.args().to_string().replace('\0', "")).unwrap();
+        unsafe {
+            if FORWARDING_TO_LOG.with(|forwarding| forwarding.get()) {
+                // The message came from SDL through `forward_to_log`, sending it back would
+                // loop forever.
+                if let Some(default) = SDL_GetDefaultLogOutputFunction() {
+                    default(null_mut(), category, priority, message.as_ptr());
+                }
+            } else {
+                SDL_LogMessage(category, priority, c"%s".as_ptr(), message.as_ptr());
+            }
+        }
+    }
+
+    fn flush(&self) {}
+}
+
 #[doc(alias = "SDL_SetLogPriorities")]
 pub fn set_log_priorities(priority: Priority) {
     let priority = priority.to_ll();
@@ -24,3 +24,58 @@ macro_rules! impl_raw_constructor(
         )+
     )
 );
+
+/// Declares a wrapper around the properties of an SDL object, with a getter for each listed
+/// key. The kind of a key is the `Properties` method that reads it, e.g. `number` for keys
+/// ending in `_NUMBER`.
+macro_rules! typed_properties(
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident {
+            $(
+                $(#[$getter_meta:meta])*
+                $getter:ident: $kind:ident($key:path),
+            )*
+        }
+    ) => (
+        $(#[$meta])*
+        #[derive(Debug)]
+        pub struct $name {
+            properties: $crate::properties::Properties,
+        }
+
+        impl $name {
+            pub(crate) fn from_ll(
+                props: sys::properties::SDL_PropertiesID,
+            ) -> Result<$name, $crate::properties::PropertiesError> {
+                if props == 0 {
+                    Err($crate::properties::PropertiesError::SdlError($crate::get_error()))
+                } else {
+                    Ok($name {
+                        properties: $crate::properties::Properties::const_from_ll(props),
+                    })
+                }
+            }
+
+            $(
+                $(#[$getter_meta])*
+                pub fn $getter(&self) -> typed_properties!(@type $kind) {
+                    self.properties.$kind($key)
+                }
+            )*
+        }
+
+        impl std::ops::Deref for $name {
+            type Target = $crate::properties::Properties;
+
+            fn deref(&self) -> &$crate::properties::Properties {
+                &self.properties
+            }
+        }
+    );
+    (@type pointer) => (*mut libc::c_void);
+    (@type number) => (Option<i64>);
+    (@type float) => (Option<f32>);
+    (@type boolean) => (bool);
+    (@type string) => (Option<String>);
+);
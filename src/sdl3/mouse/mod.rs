@@ -4,16 +4,22 @@ use crate::sys;
 use crate::video;
 use crate::Error;
 use crate::EventPump;
+use libc::c_void;
 use std::convert::TryInto;
+use std::ffi::CStr;
 use std::mem::transmute;
 use sys::mouse::{
     SDL_GetWindowRelativeMouseMode, SDL_MouseWheelDirection, SDL_SetWindowRelativeMouseMode,
 };
+use sys::stdinc::SDL_free;
 use sys::video::SDL_GetWindowID;
 
 mod relative;
 pub use self::relative::RelativeMouseState;
 
+/// The id of a mouse, only valid while it is connected.
+pub type MouseId = sys::mouse::SDL_MouseID;
+
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 #[repr(u32)]
 pub enum SystemCursor {
@@ -111,6 +117,7 @@ impl Cursor {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MouseWheelDirection {
     Normal,
     Flipped,
@@ -175,6 +182,7 @@ impl From<SDL_MouseWheelDirection> for MouseWheelDirection {
 
 #[repr(u8)]
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MouseButton {
     Unknown = 0,
     Left = sys::mouse::SDL_BUTTON_LEFT as u8,
@@ -199,6 +207,7 @@ impl MouseButton {
 }
 
 #[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MouseState {
     mouse_state: u32,
     x: f32,
@@ -398,6 +407,45 @@ pub struct MouseUtil {
 }
 
 impl MouseUtil {
+    /// Returns `true` if a mouse is connected.
+    #[doc(alias = "SDL_HasMouse")]
+    pub fn has_mouse(&self) -> bool {
+        unsafe { sys::mouse::SDL_HasMouse() }
+    }
+
+    /// Get the ids of the currently connected mice.
+    ///
+    /// This includes every device with mouse functionality, so wait for input from a mouse
+    /// before treating it as in use. Mouse events carry the id of the mouse they come from in
+    /// `which`.
+    #[doc(alias = "SDL_GetMice")]
+    pub fn mice(&self) -> Result<Vec<MouseId>, Error> {
+        let mut count = 0;
+        unsafe {
+            let mouse_ids = sys::mouse::SDL_GetMice(&mut count);
+            if mouse_ids.is_null() {
+                Err(get_error())
+            } else {
+                let ids = std::slice::from_raw_parts(mouse_ids, count as usize).to_vec();
+                SDL_free(mouse_ids as *mut c_void);
+                Ok(ids)
+            }
+        }
+    }
+
+    /// Get the name of a mouse.
+    #[doc(alias = "SDL_GetMouseNameForID")]
+    pub fn name_for_id(&self, mouse_id: MouseId) -> Result<String, Error> {
+        unsafe {
+            let name = sys::mouse::SDL_GetMouseNameForID(mouse_id);
+            if name.is_null() {
+                Err(get_error())
+            } else {
+                Ok(CStr::from_ptr(name).to_string_lossy().into_owned())
+            }
+        }
+    }
+
     /// Gets the id of the window which currently has mouse focus.
     #[doc(alias = "SDL_GetMouseFocus")]
     pub fn focused_window_id(&self) -> Option<u32> {
@@ -0,0 +1,87 @@
+//! Pen (stylus) input
+//!
+//! SDL reports pens through their own events, see [`Event::PenDown`](crate::event::Event::PenDown)
+//! and friends. Each pen has a [`PenId`] that stays the same while the pen is in proximity of
+//! the tablet, but may change between proximity sessions.
+//!
+//! Pens also generate mouse and touch events by default, those have [`PEN_MOUSE_ID`] and
+//! [`PEN_TOUCH_ID`] as their device and can be told apart with
+//! [`Event::is_from_pen`](crate::event::Event::is_from_pen).
+
+use crate::sys;
+use sys::pen::SDL_PenAxis;
+
+/// The id of a pen, only valid while the pen is in proximity.
+pub type PenId = sys::pen::SDL_PenID;
+
+/// The mouse id of mouse events synthesized from pen input.
+pub const PEN_MOUSE_ID: u32 = sys::pen::SDL_PEN_MOUSEID;
+
+/// The touch id of touch events synthesized from pen input.
+pub const PEN_TOUCH_ID: u64 = sys::pen::SDL_PEN_TOUCHID;
+
+bitflags! {
+    /// The state of a pen: whether the tip touches the tablet, which buttons are held, and
+    /// whether the eraser end is used.
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(transparent))]
+    pub struct PenInputFlags: u32 {
+        const DOWN = sys::pen::SDL_PEN_INPUT_DOWN;
+        const BUTTON_1 = sys::pen::SDL_PEN_INPUT_BUTTON_1;
+        const BUTTON_2 = sys::pen::SDL_PEN_INPUT_BUTTON_2;
+        const BUTTON_3 = sys::pen::SDL_PEN_INPUT_BUTTON_3;
+        const BUTTON_4 = sys::pen::SDL_PEN_INPUT_BUTTON_4;
+        const BUTTON_5 = sys::pen::SDL_PEN_INPUT_BUTTON_5;
+        const ERASER_TIP = sys::pen::SDL_PEN_INPUT_ERASER_TIP;
+    }
+}
+
+/// An axis of pen input, reported by [`Event::PenAxis`](crate::event::Event::PenAxis).
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PenAxis {
+    /// Pressure of the tip, from 0.0 to 1.0.
+    Pressure,
+    /// Horizontal tilt in degrees, from -90.0 to 90.0, positive to the right.
+    XTilt,
+    /// Vertical tilt in degrees, from -90.0 to 90.0, positive downwards.
+    YTilt,
+    /// Distance of the tip from the tablet, from 0.0 to 1.0.
+    Distance,
+    /// Rotation around the pen's own axis in degrees, from -180.0 to 179.9.
+    Rotation,
+    /// Position of a slider on the pen, from 0.0 to 1.0.
+    Slider,
+    /// Pressure from squeezing the pen, from 0.0 to 1.0.
+    TangentialPressure,
+    Unknown,
+}
+
+impl PenAxis {
+    pub fn from_ll(axis: SDL_PenAxis) -> PenAxis {
+        match axis {
+            SDL_PenAxis::PRESSURE => PenAxis::Pressure,
+            SDL_PenAxis::XTILT => PenAxis::XTilt,
+            SDL_PenAxis::YTILT => PenAxis::YTilt,
+            SDL_PenAxis::DISTANCE => PenAxis::Distance,
+            SDL_PenAxis::ROTATION => PenAxis::Rotation,
+            SDL_PenAxis::SLIDER => PenAxis::Slider,
+            SDL_PenAxis::TANGENTIAL_PRESSURE => PenAxis::TangentialPressure,
+            _ => PenAxis::Unknown,
+        }
+    }
+
+    pub fn to_ll(self) -> SDL_PenAxis {
+        match self {
+            PenAxis::Pressure => SDL_PenAxis::PRESSURE,
+            PenAxis::XTilt => SDL_PenAxis::XTILT,
+            PenAxis::YTilt => SDL_PenAxis::YTILT,
+            PenAxis::Distance => SDL_PenAxis::DISTANCE,
+            PenAxis::Rotation => SDL_PenAxis::ROTATION,
+            PenAxis::Slider => SDL_PenAxis::SLIDER,
+            PenAxis::TangentialPressure => SDL_PenAxis::TANGENTIAL_PRESSURE,
+            PenAxis::Unknown => SDL_PenAxis::COUNT,
+        }
+    }
+}
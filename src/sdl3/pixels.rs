@@ -331,6 +331,9 @@ pub struct PixelMasks {
     pub amask: u32,
 }
 
+/// The colorspace of pixel data, e.g. `SRGB` or `HDR10`.
+pub use sys::pixels::SDL_Colorspace as Colorspace;
+
 /// A pixel format, i.e. a set of masks that define how to pack and unpack pixel data.
 /// This is used to convert between pixel data and surface data.
 /// It wraps an SDL_PixelFormat.
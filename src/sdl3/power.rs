@@ -0,0 +1,46 @@
+//! Power status of the host machine
+//!
+//! Controllers report their batteries through
+//! [`Joystick::power_info`](crate::joystick::Joystick::power_info) and
+//! [`Event::JoyBatteryUpdated`](crate::event::Event::JoyBatteryUpdated) instead.
+
+use crate::get_error;
+use crate::sys;
+use crate::Error;
+use libc::c_int;
+use sys::power::SDL_PowerState;
+
+pub use crate::joystick::PowerLevel;
+
+/// The state of the host's power supply, see [`power_info`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PowerInfo {
+    pub state: PowerLevel,
+    /// Estimated seconds of battery life left, `None` if unknown or there is no battery.
+    pub seconds_left: Option<u32>,
+    /// Percentage of battery life left, between 0 and 100, `None` if unknown or there is no
+    /// battery.
+    pub percent_left: Option<u8>,
+}
+
+/// Returns the current power supply state of the host.
+///
+/// The values are a snapshot and may be inaccurate, especially while the battery is charging or
+/// right after the power source changed. There is no event for changes, so poll this every few
+/// seconds if needed.
+#[doc(alias = "SDL_GetPowerInfo")]
+pub fn power_info() -> Result<PowerInfo, Error> {
+    let mut seconds: c_int = -1;
+    let mut percent: c_int = -1;
+    let state = unsafe { sys::power::SDL_GetPowerInfo(&mut seconds, &mut percent) };
+
+    if state == SDL_PowerState::ERROR {
+        Err(get_error())
+    } else {
+        Ok(PowerInfo {
+            state: PowerLevel::from_ll(state),
+            seconds_left: u32::try_from(seconds).ok(),
+            percent_left: u8::try_from(percent).ok(),
+        })
+    }
+}
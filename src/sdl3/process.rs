@@ -0,0 +1,531 @@
+//! Process Control
+//!
+//! Spawns child processes and talks to them through [`IOStream`]s, so their output can be fed
+//! straight into anything else in this crate that loads from a stream.
+//!
+//! # Example
+//! ```no_run
+//! use sdl3::process::{ProcessBuilder, Stdio};
+//!
+//! let mut process = ProcessBuilder::new("git")
+//!     .args(["rev-parse", "HEAD"])
+//!     .stdout(Stdio::App)
+//!     .spawn()
+//!     .unwrap();
+//!
+//! let (output, exit_code) = process.read_output().unwrap();
+//! assert_eq!(exit_code, 0);
+//! println!("{}", String::from_utf8_lossy(&output));
+//! ```
+
+use crate::get_error;
+use crate::iostream::IOStream;
+use crate::sys;
+use crate::version;
+use crate::Error;
+use libc::{c_char, c_int, c_void};
+use std::error;
+use std::ffi::{CString, NulError};
+use std::fmt;
+use std::mem::ManuallyDrop;
+use std::ops::{Deref, DerefMut};
+use std::path::{Path, PathBuf};
+use std::ptr;
+use sys::process::{SDL_Process, SDL_ProcessIO};
+use sys::properties::{
+    SDL_CreateProperties, SDL_DestroyProperties, SDL_GetNumberProperty, SDL_GetPointerProperty,
+    SDL_SetBooleanProperty, SDL_SetNumberProperty, SDL_SetPointerProperty, SDL_SetStringProperty,
+};
+
+/// Where a standard I/O stream of a child process is connected to.
+#[derive(Copy, Clone)]
+pub enum Stdio<'a> {
+    /// The same place as the application's stream.
+    Inherited,
+    /// Nowhere: `/dev/null` on POSIX systems, `NUL:` on Windows.
+    Null,
+    /// A new pipe that the application reads or writes through the [`Process`].
+    App,
+    /// An existing stream backed by a file or by another process's I/O.
+    ///
+    /// The stream only has to live until the process is spawned.
+    Redirect(&'a IOStream<'a>),
+}
+
+impl Stdio<'_> {
+    fn to_ll(self) -> SDL_ProcessIO {
+        match self {
+            Stdio::Inherited => SDL_ProcessIO::INHERITED,
+            Stdio::Null => SDL_ProcessIO::NULL,
+            Stdio::App => SDL_ProcessIO::APP,
+            Stdio::Redirect(_) => SDL_ProcessIO::REDIRECT,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ProcessError {
+    InvalidString(NulError),
+    InvalidPath(PathBuf),
+    /// The working directory can only be set with SDL 3.4 or newer.
+    WorkingDirectoryUnsupported,
+    SdlError(Error),
+}
+
+impl fmt::Display for ProcessError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::ProcessError::*;
+
+        match *self {
+            InvalidString(ref e) => write!(f, "Invalid string: {}", e),
+            InvalidPath(ref p) => write!(f, "Invalid path: {}", p.display()),
+            WorkingDirectoryUnsupported => {
+                write!(f, "Setting the working directory requires SDL 3.4")
+            }
+            SdlError(ref e) => write!(f, "SDL error: {}", e),
+        }
+    }
+}
+
+impl error::Error for ProcessError {}
+
+// Not yet in the bindings, only understood by SDL 3.4 and newer.
+const PROP_PROCESS_CREATE_WORKING_DIRECTORY_STRING: &std::ffi::CStr =
+    c"SDL.process.create.working_directory";
+
+/// Describes a process to spawn, similar to [`std::process::Command`].
+pub struct ProcessBuilder<'a> {
+    args: Vec<String>,
+    env_clear: bool,
+    env: Vec<(String, Option<String>)>,
+    working_directory: Option<PathBuf>,
+    stdin: Option<Stdio<'a>>,
+    stdout: Option<Stdio<'a>>,
+    stderr: Option<Stdio<'a>>,
+    stderr_to_stdout: bool,
+    background: bool,
+}
+
+impl<'a> ProcessBuilder<'a> {
+    /// Initializes a new `ProcessBuilder` for running `program`.
+    ///
+    /// By default the process inherits the environment, standard output and standard error of
+    /// the application, and its standard input is [`Stdio::Null`].
+    pub fn new(program: &str) -> ProcessBuilder<'a> {
+        ProcessBuilder {
+            args: vec![program.to_owned()],
+            env_clear: false,
+            env: Vec::new(),
+            working_directory: None,
+            stdin: None,
+            stdout: None,
+            stderr: None,
+            stderr_to_stdout: false,
+            background: false,
+        }
+    }
+
+    /// Adds an argument to pass to the program.
+    pub fn arg(&mut self, arg: &str) -> &mut ProcessBuilder<'a> {
+        self.args.push(arg.to_owned());
+        self
+    }
+
+    /// Adds several arguments to pass to the program.
+    pub fn args<I, S>(&mut self, args: I) -> &mut ProcessBuilder<'a>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.args
+            .extend(args.into_iter().map(|arg| arg.as_ref().to_owned()));
+        self
+    }
+
+    /// Sets an environment variable for the process.
+    pub fn env(&mut self, key: &str, value: &str) -> &mut ProcessBuilder<'a> {
+        self.env.push((key.to_owned(), Some(value.to_owned())));
+        self
+    }
+
+    /// Removes an environment variable from the environment of the process.
+    pub fn env_remove(&mut self, key: &str) -> &mut ProcessBuilder<'a> {
+        self.env.push((key.to_owned(), None));
+        self
+    }
+
+    /// Starts the process with an empty environment, before applying the variables set with
+    /// [`env`](Self::env).
+    pub fn env_clear(&mut self) -> &mut ProcessBuilder<'a> {
+        self.env_clear = true;
+        self.env.clear();
+        self
+    }
+
+    /// Sets the working directory of the process.
+    ///
+    /// This requires SDL 3.4 or newer, [`spawn`](Self::spawn) fails on older versions.
+    pub fn current_dir(&mut self, dir: impl AsRef<Path>) -> &mut ProcessBuilder<'a> {
+        self.working_directory = Some(dir.as_ref().to_owned());
+        self
+    }
+
+    /// Sets where standard input of the process comes from.
+    pub fn stdin(&mut self, stdio: Stdio<'a>) -> &mut ProcessBuilder<'a> {
+        self.stdin = Some(stdio);
+        self
+    }
+
+    /// Sets where standard output of the process goes to.
+    pub fn stdout(&mut self, stdio: Stdio<'a>) -> &mut ProcessBuilder<'a> {
+        self.stdout = Some(stdio);
+        self
+    }
+
+    /// Sets where standard error of the process goes to.
+    pub fn stderr(&mut self, stdio: Stdio<'a>) -> &mut ProcessBuilder<'a> {
+        self.stderr = Some(stdio);
+        self
+    }
+
+    /// Merges standard error of the process into its standard output.
+    ///
+    /// This has no effect if [`stderr`](Self::stderr) is set.
+    pub fn stderr_to_stdout(&mut self, enabled: bool) -> &mut ProcessBuilder<'a> {
+        self.stderr_to_stdout = enabled;
+        self
+    }
+
+    /// Runs the process in the background.
+    ///
+    /// Standard I/O then defaults to [`Stdio::Null`] and the exit code of the process is
+    /// always 0.
+    pub fn background(&mut self, enabled: bool) -> &mut ProcessBuilder<'a> {
+        self.background = enabled;
+        self
+    }
+
+    /// Spawns the process.
+    #[doc(alias = "SDL_CreateProcessWithProperties")]
+    pub fn spawn(&self) -> Result<Process, ProcessError> {
+        use self::ProcessError::*;
+
+        let args = self
+            .args
+            .iter()
+            .map(|arg| CString::new(arg.as_str()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(InvalidString)?;
+        let mut argv: Vec<*const c_char> = args.iter().map(|arg| arg.as_ptr()).collect();
+        argv.push(ptr::null());
+
+        let working_directory = match &self.working_directory {
+            Some(dir) => {
+                let v = version::version();
+                if (v.major, v.minor) < (3, 4) {
+                    return Err(WorkingDirectoryUnsupported);
+                }
+                let Some(dir_str) = dir.to_str() else {
+                    return Err(InvalidPath(dir.clone()));
+                };
+                Some(CString::new(dir_str).map_err(InvalidString)?)
+            }
+            None => None,
+        };
+
+        let environment = if self.env_clear || !self.env.is_empty() {
+            Some(Environment::new(self.env_clear, &self.env)?)
+        } else {
+            None
+        };
+
+        unsafe {
+            let props = SDL_CreateProperties();
+            SDL_SetPointerProperty(
+                props,
+                sys::process::SDL_PROP_PROCESS_CREATE_ARGS_POINTER,
+                argv.as_mut_ptr() as *mut c_void,
+            );
+            if let Some(environment) = &environment {
+                SDL_SetPointerProperty(
+                    props,
+                    sys::process::SDL_PROP_PROCESS_CREATE_ENVIRONMENT_POINTER,
+                    environment.raw as *mut c_void,
+                );
+            }
+            if let Some(dir) = &working_directory {
+                SDL_SetStringProperty(
+                    props,
+                    PROP_PROCESS_CREATE_WORKING_DIRECTORY_STRING.as_ptr(),
+                    dir.as_ptr(),
+                );
+            }
+
+            if let Some(stdin) = self.stdin {
+                set_stdio(
+                    props,
+                    sys::process::SDL_PROP_PROCESS_CREATE_STDIN_NUMBER,
+                    sys::process::SDL_PROP_PROCESS_CREATE_STDIN_POINTER,
+                    stdin,
+                );
+            }
+            if let Some(stdout) = self.stdout {
+                set_stdio(
+                    props,
+                    sys::process::SDL_PROP_PROCESS_CREATE_STDOUT_NUMBER,
+                    sys::process::SDL_PROP_PROCESS_CREATE_STDOUT_POINTER,
+                    stdout,
+                );
+            }
+            if let Some(stderr) = self.stderr {
+                set_stdio(
+                    props,
+                    sys::process::SDL_PROP_PROCESS_CREATE_STDERR_NUMBER,
+                    sys::process::SDL_PROP_PROCESS_CREATE_STDERR_POINTER,
+                    stderr,
+                );
+            }
+            SDL_SetBooleanProperty(
+                props,
+                sys::process::SDL_PROP_PROCESS_CREATE_STDERR_TO_STDOUT_BOOLEAN,
+                self.stderr_to_stdout,
+            );
+            SDL_SetBooleanProperty(
+                props,
+                sys::process::SDL_PROP_PROCESS_CREATE_BACKGROUND_BOOLEAN,
+                self.background,
+            );
+
+            let raw = sys::process::SDL_CreateProcessWithProperties(props);
+            SDL_DestroyProperties(props);
+
+            if raw.is_null() {
+                Err(SdlError(get_error()))
+            } else {
+                Ok(Process { raw })
+            }
+        }
+    }
+}
+
+unsafe fn set_stdio(
+    props: sys::properties::SDL_PropertiesID,
+    option_name: *const c_char,
+    source_name: *const c_char,
+    stdio: Stdio,
+) {
+    SDL_SetNumberProperty(props, option_name, stdio.to_ll().0.into());
+    if let Stdio::Redirect(stream) = stdio {
+        SDL_SetPointerProperty(props, source_name, stream.raw() as *mut c_void);
+    }
+}
+
+/// An `SDL_Environment` that only lives while the process is being created.
+struct Environment {
+    raw: *mut sys::stdinc::SDL_Environment,
+}
+
+impl Environment {
+    fn new(clear: bool, vars: &[(String, Option<String>)]) -> Result<Environment, ProcessError> {
+        let raw = unsafe { sys::stdinc::SDL_CreateEnvironment(!clear) };
+        if raw.is_null() {
+            return Err(ProcessError::SdlError(get_error()));
+        }
+        let environment = Environment { raw };
+
+        for (key, value) in vars {
+            let key = CString::new(key.as_str()).map_err(ProcessError::InvalidString)?;
+            let ok = match value {
+                Some(value) => {
+                    let value =
+                        CString::new(value.as_str()).map_err(ProcessError::InvalidString)?;
+                    unsafe {
+                        sys::stdinc::SDL_SetEnvironmentVariable(
+                            raw,
+                            key.as_ptr(),
+                            value.as_ptr(),
+                            true,
+                        )
+                    }
+                }
+                None => unsafe { sys::stdinc::SDL_UnsetEnvironmentVariable(raw, key.as_ptr()) },
+            };
+            if !ok {
+                return Err(ProcessError::SdlError(get_error()));
+            }
+        }
+        Ok(environment)
+    }
+}
+
+impl Drop for Environment {
+    fn drop(&mut self) {
+        unsafe { sys::stdinc::SDL_DestroyEnvironment(self.raw) }
+    }
+}
+
+/// A running (or finished) child process.
+///
+/// Dropping a `Process` does not stop it, use [`kill`](Self::kill) for that.
+pub struct Process {
+    raw: *mut SDL_Process,
+}
+
+// The process object is not bound to the thread that created it, it just must not be used
+// from several threads at once.
+unsafe impl Send for Process {}
+
+/// A standard I/O stream of a [`Process`].
+///
+/// The stream belongs to the process and is closed together with it, so it can't outlive the
+/// borrow it was obtained from.
+pub struct ProcessStream<'a> {
+    stream: ManuallyDrop<IOStream<'a>>,
+}
+
+impl<'a> Deref for ProcessStream<'a> {
+    type Target = IOStream<'a>;
+
+    #[inline]
+    fn deref(&self) -> &IOStream<'a> {
+        &self.stream
+    }
+}
+
+impl<'a> DerefMut for ProcessStream<'a> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut IOStream<'a> {
+        &mut self.stream
+    }
+}
+
+impl Process {
+    #[inline]
+    pub const fn raw(&self) -> *mut SDL_Process {
+        self.raw
+    }
+
+    fn properties(&self) -> sys::properties::SDL_PropertiesID {
+        unsafe { sys::process::SDL_GetProcessProperties(self.raw) }
+    }
+
+    /// Returns the OS process ID.
+    pub fn pid(&self) -> i64 {
+        unsafe {
+            SDL_GetNumberProperty(
+                self.properties(),
+                sys::process::SDL_PROP_PROCESS_PID_NUMBER,
+                0,
+            )
+        }
+    }
+
+    /// Returns the stream writing to standard input of the process.
+    ///
+    /// Only available if the process was spawned with standard input set to [`Stdio::App`].
+    /// Writes may be short if the process isn't reading its input, e.g. because it is
+    /// blocked on writing output that nobody reads.
+    #[doc(alias = "SDL_GetProcessInput")]
+    pub fn input(&mut self) -> Result<ProcessStream<'_>, Error> {
+        unsafe { Self::stream(sys::process::SDL_GetProcessInput(self.raw)) }
+    }
+
+    /// Returns the non-blocking stream reading from standard output of the process.
+    ///
+    /// Only available if the process was spawned with standard output set to [`Stdio::App`].
+    /// Reads return 0 with [`IOStatus::NotReady`](crate::iostream::IOStatus::NotReady) while
+    /// no output is available.
+    #[doc(alias = "SDL_GetProcessOutput")]
+    pub fn output(&mut self) -> Result<ProcessStream<'_>, Error> {
+        unsafe { Self::stream(sys::process::SDL_GetProcessOutput(self.raw)) }
+    }
+
+    /// Returns the non-blocking stream reading from standard error of the process.
+    ///
+    /// Only available if the process was spawned with standard error set to [`Stdio::App`].
+    pub fn error_output(&mut self) -> Result<ProcessStream<'_>, Error> {
+        let raw = unsafe {
+            SDL_GetPointerProperty(
+                self.properties(),
+                sys::process::SDL_PROP_PROCESS_STDERR_POINTER,
+                ptr::null_mut(),
+            )
+        };
+        if raw.is_null() {
+            return Err(Error("Process standard error is not available".to_owned()));
+        }
+        unsafe { Self::stream(raw as *mut sys::iostream::SDL_IOStream) }
+    }
+
+    unsafe fn stream<'a>(
+        raw: *mut sys::iostream::SDL_IOStream,
+    ) -> Result<ProcessStream<'a>, Error> {
+        IOStream::from_ll_or_error(raw).map(|stream| ProcessStream {
+            stream: ManuallyDrop::new(stream),
+        })
+    }
+
+    /// Closes standard input of the process, signalling the end of its input.
+    pub fn close_input(&mut self) {
+        unsafe {
+            SDL_SetPointerProperty(
+                self.properties(),
+                sys::process::SDL_PROP_PROCESS_STDIN_POINTER,
+                ptr::null_mut(),
+            );
+        }
+    }
+
+    /// Reads all of standard output until the process exits, and returns it together with the
+    /// exit code.
+    ///
+    /// Only available if the process was spawned with standard output set to [`Stdio::App`].
+    #[doc(alias = "SDL_ReadProcess")]
+    pub fn read_output(&mut self) -> Result<(Vec<u8>, i32), Error> {
+        let mut size = 0;
+        let mut exit_code: c_int = 0;
+        unsafe {
+            let data = sys::process::SDL_ReadProcess(self.raw, &mut size, &mut exit_code);
+            if data.is_null() {
+                return Err(get_error());
+            }
+            let output = std::slice::from_raw_parts(data as *const u8, size).to_vec();
+            sys::stdinc::SDL_free(data);
+            Ok((output, exit_code))
+        }
+    }
+
+    /// Returns the exit code if the process has exited.
+    ///
+    /// With `block` set, waits for the process to exit first. Read all piped output before
+    /// blocking, or the process may never exit.
+    ///
+    /// The exit code is negative if the process was terminated by a signal, and -255 if it
+    /// ended in some other abnormal way.
+    #[doc(alias = "SDL_WaitProcess")]
+    pub fn wait(&mut self, block: bool) -> Option<i32> {
+        let mut exit_code: c_int = 0;
+        if unsafe { sys::process::SDL_WaitProcess(self.raw, block, &mut exit_code) } {
+            Some(exit_code)
+        } else {
+            None
+        }
+    }
+
+    /// Stops the process, immediately if `force` is set, otherwise by asking it to exit.
+    #[doc(alias = "SDL_KillProcess")]
+    pub fn kill(&mut self, force: bool) -> Result<(), Error> {
+        if unsafe { sys::process::SDL_KillProcess(self.raw, force) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+}
+
+impl Drop for Process {
+    #[doc(alias = "SDL_DestroyProcess")]
+    fn drop(&mut self) {
+        unsafe { sys::process::SDL_DestroyProcess(self.raw) }
+    }
+}
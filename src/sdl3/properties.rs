@@ -1,10 +1,14 @@
 use libc::c_char;
 use libc::c_void;
+use std::error;
 use std::ffi::CStr;
 use std::ffi::CString;
 use std::ffi::NulError;
+use std::fmt;
+use std::ops::Deref;
 use std::ptr;
 use std::str::Utf8Error;
+use std::vec;
 use sys::properties::SDL_PropertiesID;
 
 use crate::get_error;
@@ -19,6 +23,29 @@ pub enum PropertiesError {
     SdlError(Error),
 }
 
+impl fmt::Display for PropertiesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::PropertiesError::*;
+
+        match *self {
+            ArgumentError(ref e) => write!(f, "Invalid argument: {}", e),
+            StringError(ref e) => write!(f, "Invalid string: {}", e),
+            NullPointer => write!(f, "Property is not set"),
+            SdlError(ref e) => write!(f, "SDL error: {}", e),
+        }
+    }
+}
+
+impl error::Error for PropertiesError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            PropertiesError::ArgumentError(e) => Some(e),
+            PropertiesError::StringError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Properties {
     internal: sys::properties::SDL_PropertiesID,
@@ -40,30 +67,103 @@ unsafe extern "C" fn enumerate(
     props: SDL_PropertiesID,
     name: *const c_char,
 ) {
-    let properties: &Properties = std::mem::transmute(&props);
+    let properties = Properties::const_from_ll(props);
     let callback_ptr = userdata as *mut EnumerateCallback;
     let name = CStr::from_ptr(name);
     match name.to_str() {
-        Ok(name) => (*callback_ptr)(properties, Ok(name)),
-        Err(error) => (*callback_ptr)(properties, Err(PropertiesError::StringError(error))),
+        Ok(name) => (*callback_ptr)(&properties, Ok(name)),
+        Err(error) => (*callback_ptr)(&properties, Err(PropertiesError::StringError(error))),
     }
 }
 
-pub type CleanupBox = fn(*mut c_void);
-unsafe extern "C" fn cleanup_box(userdata: *mut c_void, value: *mut c_void) {
-    let callback_ptr = userdata as *mut CleanupBox;
-    (*callback_ptr)(value);
+unsafe extern "C" fn collect_entries(
+    userdata: *mut c_void,
+    props: SDL_PropertiesID,
+    name: *const c_char,
+) {
+    let entries = &mut *(userdata as *mut Vec<(String, PropertyType)>);
+    let property_type = sys::properties::SDL_GetPropertyType(props, name);
+    let name = CStr::from_ptr(name).to_string_lossy().into_owned();
+    entries.push((name, property_type));
 }
 
-pub type CleanupCallback = Box<dyn FnOnce(*mut c_void)>;
-unsafe extern "C" fn cleanup_custom(userdata: *mut c_void, value: *mut c_void) {
-    let callback_ptr = userdata as *mut CleanupCallback;
-    let callback = Box::from_raw(callback_ptr);
-    (*callback)(value);
+unsafe extern "C" fn cleanup_box<T>(_userdata: *mut c_void, value: *mut c_void) {
+    drop(Box::from_raw(value as *mut T));
+}
+
+unsafe extern "C" fn cleanup_custom<T, F: FnOnce(*mut T)>(
+    userdata: *mut c_void,
+    value: *mut c_void,
+) {
+    let callback = Box::from_raw(userdata as *mut F);
+    callback(value as *mut T);
 }
 
 pub use sys::properties::SDL_PropertyType as PropertyType;
 
+/// The value of a property of any type, see [`Properties::get_value`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyValue {
+    Pointer(*mut c_void),
+    String(String),
+    Number(i64),
+    Float(f32),
+    Boolean(bool),
+}
+
+impl PropertyValue {
+    pub fn property_type(&self) -> PropertyType {
+        match self {
+            PropertyValue::Pointer(_) => PropertyType::POINTER,
+            PropertyValue::String(_) => PropertyType::STRING,
+            PropertyValue::Number(_) => PropertyType::NUMBER,
+            PropertyValue::Float(_) => PropertyType::FLOAT,
+            PropertyValue::Boolean(_) => PropertyType::BOOLEAN,
+        }
+    }
+}
+
+/// Keeps a group of properties locked, see [`Properties::lock`].
+///
+/// The properties are unlocked when this is dropped.
+pub struct PropertiesLock<'a> {
+    properties: &'a Properties,
+}
+
+impl Deref for PropertiesLock<'_> {
+    type Target = Properties;
+
+    fn deref(&self) -> &Properties {
+        self.properties
+    }
+}
+
+impl Drop for PropertiesLock<'_> {
+    #[doc(alias = "SDL_UnlockProperties")]
+    fn drop(&mut self) {
+        unsafe { sys::properties::SDL_UnlockProperties(self.properties.internal) }
+    }
+}
+
+/// The names and types of a group of properties, see [`Properties::iter`].
+pub struct PropertiesIter {
+    entries: vec::IntoIter<(String, PropertyType)>,
+}
+
+impl Iterator for PropertiesIter {
+    type Item = (String, PropertyType);
+
+    fn next(&mut self) -> Option<(String, PropertyType)> {
+        self.entries.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.size_hint()
+    }
+}
+
+impl ExactSizeIterator for PropertiesIter {}
+
 impl Properties {
     #[doc(alias = "SDL_CreateProperties")]
     pub fn new() -> Result<Self, PropertiesError> {
@@ -105,20 +205,54 @@ impl Properties {
         }
     }
 
+    pub fn raw(&self) -> SDL_PropertiesID {
+        self.internal
+    }
+
+    /// Locks the properties until the returned guard is dropped, so that other threads can't
+    /// change them in the meantime.
+    ///
+    /// The lock is recursive, the properties can still be used through the guard.
     #[doc(alias = "SDL_LockProperties")]
-    pub fn lock(&mut self) -> Result<(), PropertiesError> {
+    pub fn lock(&self) -> Result<PropertiesLock<'_>, PropertiesError> {
+        if unsafe { sys::properties::SDL_LockProperties(self.internal) } {
+            Ok(PropertiesLock { properties: self })
+        } else {
+            Err(PropertiesError::SdlError(get_error()))
+        }
+    }
+
+    // Getters for the known keys of `typed_properties!`, which SDL exposes as C strings.
+
+    pub(crate) fn pointer(&self, name: *const c_char) -> *mut c_void {
+        unsafe { sys::properties::SDL_GetPointerProperty(self.internal, name, ptr::null_mut()) }
+    }
+
+    pub(crate) fn number(&self, name: *const c_char) -> Option<i64> {
+        let _lock = self.lock().ok()?;
         unsafe {
-            if !sys::properties::SDL_LockProperties(self.internal) {
-                return Err(PropertiesError::SdlError(get_error()));
-            }
+            sys::properties::SDL_HasProperty(self.internal, name)
+                .then(|| sys::properties::SDL_GetNumberProperty(self.internal, name, 0))
         }
-        Ok(())
     }
 
-    #[doc(alias = "SDL_UnlockProperties")]
-    pub fn unlock(&mut self) {
+    pub(crate) fn float(&self, name: *const c_char) -> Option<f32> {
+        let _lock = self.lock().ok()?;
         unsafe {
-            sys::properties::SDL_UnlockProperties(self.internal);
+            sys::properties::SDL_HasProperty(self.internal, name)
+                .then(|| sys::properties::SDL_GetFloatProperty(self.internal, name, 0.0))
+        }
+    }
+
+    pub(crate) fn boolean(&self, name: *const c_char) -> bool {
+        unsafe { sys::properties::SDL_GetBooleanProperty(self.internal, name, false) }
+    }
+
+    pub(crate) fn string(&self, name: *const c_char) -> Option<String> {
+        let _lock = self.lock().ok()?;
+        unsafe {
+            let value = sys::properties::SDL_GetStringProperty(self.internal, name, ptr::null());
+            (!value.is_null()).then(|| CStr::from_ptr(value).to_string_lossy().into_owned())
         }
     }
 
@@ -133,13 +267,21 @@ impl Properties {
         }
     }
 
+    /// Sets a pointer property, `cleanup` is called with the pointer when the property is
+    /// cleared, replaced or destroyed.
+    ///
+    /// The cleanup may run on whichever thread changes the property. It also runs right away if
+    /// the property can't be set.
     #[doc(alias = "SDL_SetPointerPropertyWithCleanup")]
-    pub fn set_with_cleanup<T>(
+    pub fn set_with_cleanup<T, F>(
         &self,
         name: &str,
         value: *mut T,
-        cleanup: Box<dyn FnOnce(*mut T)>,
-    ) -> Result<(), PropertiesError> {
+        cleanup: F,
+    ) -> Result<(), PropertiesError>
+    where
+        F: FnOnce(*mut T) + Send + 'static,
+    {
         cstring!(name);
         let value_ptr = value as *mut c_void;
         let cleanup_ptr = Box::into_raw(Box::new(cleanup)) as *mut c_void;
@@ -148,7 +290,7 @@ impl Properties {
                 self.internal,
                 name.as_ptr(),
                 value_ptr,
-                Some(cleanup_custom),
+                Some(cleanup_custom::<T, F>),
                 cleanup_ptr,
             )
         } {
@@ -199,16 +341,71 @@ impl Properties {
 
     #[doc(alias = "SDL_EnumerateProperties")]
     pub fn enumerate(&self, callback: EnumerateCallback) -> Result<(), PropertiesError> {
-        let callback_ptr = Box::into_raw(Box::new(callback)) as *mut c_void;
+        let callback_ptr = Box::into_raw(Box::new(callback));
+        let result = unsafe {
+            sys::properties::SDL_EnumerateProperties(
+                self.internal,
+                Some(enumerate),
+                callback_ptr as *mut c_void,
+            )
+        };
+        drop(unsafe { Box::from_raw(callback_ptr) });
+        if result {
+            Ok(())
+        } else {
+            Err(PropertiesError::SdlError(get_error()))
+        }
+    }
+
+    /// Returns the names and types of all properties at the time of the call.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use sdl3::properties::{Properties, PropertyType, Setter};
+    ///
+    /// let properties = Properties::new().unwrap();
+    /// properties.set("answer", 42i64).unwrap();
+    /// for (name, property_type) in properties.iter().unwrap() {
+    ///     if property_type == PropertyType::NUMBER {
+    ///         println!("{name} = {:?}", properties.get_value(&name));
+    ///     }
+    /// }
+    /// ```
+    #[doc(alias = "SDL_EnumerateProperties")]
+    pub fn iter(&self) -> Result<PropertiesIter, PropertiesError> {
+        let mut entries: Vec<(String, PropertyType)> = Vec::new();
         if unsafe {
-            sys::properties::SDL_EnumerateProperties(self.internal, Some(enumerate), callback_ptr)
+            sys::properties::SDL_EnumerateProperties(
+                self.internal,
+                Some(collect_entries),
+                &mut entries as *mut _ as *mut c_void,
+            )
         } {
-            Ok(())
+            Ok(PropertiesIter {
+                entries: entries.into_iter(),
+            })
         } else {
             Err(PropertiesError::SdlError(get_error()))
         }
     }
 
+    /// Returns the value of a property of any type, or `None` if it isn't set.
+    pub fn get_value(&self, name: &str) -> Result<Option<PropertyValue>, PropertiesError> {
+        // Keep the type and value consistent in case another thread changes the property.
+        let lock = self.lock()?;
+        let value = match lock.get_type(name)? {
+            PropertyType::POINTER => {
+                PropertyValue::Pointer(lock.get(name, ptr::null_mut::<c_void>())?)
+            }
+            PropertyType::STRING => PropertyValue::String(lock.get(name, String::new())?),
+            PropertyType::NUMBER => PropertyValue::Number(lock.get(name, 0)?),
+            PropertyType::FLOAT => PropertyValue::Float(lock.get(name, 0.0)?),
+            PropertyType::BOOLEAN => PropertyValue::Boolean(lock.get(name, false)?),
+            _ => return Ok(None),
+        };
+        Ok(Some(value))
+    }
+
     #[doc(alias = "SDL_ClearProperty")]
     pub fn clear(&mut self, name: &str) -> Result<(), PropertiesError> {
         cstring!(name);
@@ -219,17 +416,25 @@ impl Properties {
         }
     }
 
+    /// Borrows a pointer property set with a `Box<T>`, while holding a lock on the properties.
+    ///
+    /// Returns [`PropertiesError::NullPointer`] if the property is missing or not a pointer.
+    ///
+    /// # Safety
+    /// A non-null pointer property `name` must point to a valid `T`, e.g. one set with a
+    /// `Box<T>` of the same `T`. The pointer is not checked beyond that.
     #[doc(alias = "SDL_GetPointerProperty")]
-    pub fn with<T>(&mut self, name: &str, with: fn(&T)) -> Result<(), PropertiesError> {
-        self.lock()?;
-        let pointer: *mut T = self.get(name, ptr::null_mut())?;
+    pub unsafe fn with<T, R>(
+        &self,
+        name: &str,
+        with: impl FnOnce(&T) -> R,
+    ) -> Result<R, PropertiesError> {
+        let lock = self.lock()?;
+        let pointer: *mut T = lock.get(name, ptr::null_mut())?;
         if pointer.is_null() {
             return Err(PropertiesError::NullPointer);
         }
-        let reference = unsafe { &mut *pointer };
-        with(reference);
-        self.unlock();
-        Ok(())
+        Ok(with(&*pointer))
     }
 }
 
@@ -289,6 +494,13 @@ impl Setter<&str> for Properties {
     }
 }
 
+impl Setter<String> for Properties {
+    #[doc(alias = "SDL_SetStringProperty")]
+    fn set(&self, name: &str, value: String) -> Result<(), PropertiesError> {
+        self.set(name, value.as_str())
+    }
+}
+
 impl<T> Setter<*mut T> for Properties {
     #[doc(alias = "SDL_SetPointerProperty")]
     fn set(&self, name: &str, value: *mut T) -> Result<(), PropertiesError> {
@@ -312,20 +524,13 @@ impl<T> Setter<Box<T>> for Properties {
     fn set(&self, name: &str, value: Box<T>) -> Result<(), PropertiesError> {
         cstring!(name);
         let value_ptr: *mut c_void = Box::into_raw(value) as *mut c_void;
-        let cleanup: CleanupBox = |value: *mut c_void| {
-            let value = value as *mut T;
-            unsafe {
-                drop(Box::from_raw(value));
-            }
-        };
-        let cleanup_ptr = Box::into_raw(Box::new(cleanup)) as *mut c_void;
         if unsafe {
             sys::properties::SDL_SetPointerPropertyWithCleanup(
                 self.internal,
                 name.as_ptr(),
                 value_ptr,
-                Some(cleanup_box),
-                cleanup_ptr,
+                Some(cleanup_box::<T>),
+                ptr::null_mut(),
             )
         } {
             Ok(())
@@ -335,6 +540,18 @@ impl<T> Setter<Box<T>> for Properties {
     }
 }
 
+impl Setter<PropertyValue> for Properties {
+    fn set(&self, name: &str, value: PropertyValue) -> Result<(), PropertiesError> {
+        match value {
+            PropertyValue::Pointer(value) => self.set(name, value),
+            PropertyValue::String(value) => self.set(name, value),
+            PropertyValue::Number(value) => self.set(name, value),
+            PropertyValue::Float(value) => self.set(name, value),
+            PropertyValue::Boolean(value) => self.set(name, value),
+        }
+    }
+}
+
 pub trait Getter<T> {
     fn get(&self, name: &str, default: T) -> Result<T, PropertiesError>;
 }
@@ -381,6 +598,15 @@ impl Getter<i64> for Properties {
     }
 }
 
+impl Getter<String> for Properties {
+    #[doc(alias = "SDL_GetStringProperty")]
+    fn get(&self, name: &str, default: String) -> Result<String, PropertiesError> {
+        // The string SDL returns is only valid until the property changes.
+        let lock = self.lock()?;
+        lock.get_string(name, &default)
+    }
+}
+
 impl<T> Getter<*mut T> for Properties {
     #[doc(alias = "SDL_GetPointerProperty")]
     fn get(&self, name: &str, default: *mut T) -> Result<*mut T, PropertiesError> {
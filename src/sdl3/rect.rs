@@ -904,6 +904,66 @@ impl std::iter::Sum for Point {
     }
 }
 
+/// The serialized form of [`Rect`] and [`Point`], which hide their fields behind SDL's structs.
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::{Point, Rect};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "Rect")]
+    struct RectDef {
+        x: i32,
+        y: i32,
+        w: u32,
+        h: u32,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "Point")]
+    struct PointDef {
+        x: i32,
+        y: i32,
+    }
+
+    impl Serialize for Rect {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            RectDef {
+                x: self.x(),
+                y: self.y(),
+                w: self.width(),
+                h: self.height(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    /// Like [`Rect::new`], the size is clamped to valid values.
+    impl<'de> Deserialize<'de> for Rect {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Rect, D::Error> {
+            let RectDef { x, y, w, h } = RectDef::deserialize(deserializer)?;
+            Ok(Rect::new(x, y, w, h))
+        }
+    }
+
+    impl Serialize for Point {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            PointDef {
+                x: self.x(),
+                y: self.y(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Point {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Point, D::Error> {
+            let PointDef { x, y } = PointDef::deserialize(deserializer)?;
+            Ok(Point::new(x, y))
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::{max_int_value, min_int_value, Point, Rect};
@@ -31,6 +31,7 @@
 use crate::common::{validate_int, IntegerOrSdlError};
 use crate::get_error;
 use crate::pixels;
+use crate::properties::PropertiesError;
 use crate::rect::Point;
 use crate::rect::Rect;
 use crate::surface::{Surface, SurfaceContext, SurfaceRef};
@@ -38,7 +39,7 @@ use crate::sys;
 use crate::video::{Window, WindowContext};
 use crate::Error;
 use libc::{c_double, c_int, c_void};
-use pixels::PixelFormat;
+use pixels::{Colorspace, PixelFormat};
 use std::convert::{Into, TryFrom, TryInto};
 use std::error;
 use std::ffi::CStr;
@@ -1106,6 +1107,12 @@ impl<T: RenderTarget> Canvas<T> {
         self.context.raw()
     }
 
+    /// Returns the properties of the renderer, e.g. its limits and native handles.
+    #[doc(alias = "SDL_GetRendererProperties")]
+    pub fn get_properties(&self) -> Result<RendererProperties, PropertiesError> {
+        RendererProperties::from_ll(unsafe { sys::render::SDL_GetRendererProperties(self.raw()) })
+    }
+
     /// Sets the color used for drawing operations (Rect, Line and Clear).
     #[doc(alias = "SDL_SetRenderDrawColor")]
     pub fn set_draw_color<C: Into<pixels::Color>>(&mut self, color: C) {
@@ -2271,6 +2278,155 @@ impl error::Error for UpdateTextureYUVError {
     }
 }
 
+typed_properties! {
+    /// The properties of a renderer, see [`Canvas::get_properties`].
+    pub struct RendererProperties {
+        /// The name of the rendering driver.
+        name: string(sys::render::SDL_PROP_RENDERER_NAME_STRING),
+        /// The `SDL_Window` of the renderer, if any.
+        window: pointer(sys::render::SDL_PROP_RENDERER_WINDOW_POINTER),
+        /// The `SDL_Surface` of a software renderer.
+        surface: pointer(sys::render::SDL_PROP_RENDERER_SURFACE_POINTER),
+        /// The current vsync setting.
+        vsync: number(sys::render::SDL_PROP_RENDERER_VSYNC_NUMBER),
+        /// The maximum width and height of textures.
+        max_texture_size: number(sys::render::SDL_PROP_RENDERER_MAX_TEXTURE_SIZE_NUMBER),
+        /// Whether the output is in a HDR colorspace and has HDR headroom.
+        hdr_enabled: boolean(sys::render::SDL_PROP_RENDERER_HDR_ENABLED_BOOLEAN),
+        /// The value of SDR white in the linear colorspace.
+        sdr_white_point: float(sys::render::SDL_PROP_RENDERER_SDR_WHITE_POINT_FLOAT),
+        /// The additional high dynamic range that can be displayed, in terms of the SDR white
+        /// point.
+        hdr_headroom: float(sys::render::SDL_PROP_RENDERER_HDR_HEADROOM_FLOAT),
+        /// The `IDirect3DDevice9` of the renderer.
+        d3d9_device: pointer(sys::render::SDL_PROP_RENDERER_D3D9_DEVICE_POINTER),
+        /// The `ID3D11Device` of the renderer.
+        d3d11_device: pointer(sys::render::SDL_PROP_RENDERER_D3D11_DEVICE_POINTER),
+        /// The `IDXGISwapChain1` of the renderer.
+        d3d11_swapchain: pointer(sys::render::SDL_PROP_RENDERER_D3D11_SWAPCHAIN_POINTER),
+        /// The `ID3D12Device` of the renderer.
+        d3d12_device: pointer(sys::render::SDL_PROP_RENDERER_D3D12_DEVICE_POINTER),
+        /// The `IDXGISwapChain4` of the renderer.
+        d3d12_swapchain: pointer(sys::render::SDL_PROP_RENDERER_D3D12_SWAPCHAIN_POINTER),
+        /// The `ID3D12CommandQueue` of the renderer.
+        d3d12_command_queue: pointer(sys::render::SDL_PROP_RENDERER_D3D12_COMMAND_QUEUE_POINTER),
+        /// The `VkInstance` of the renderer.
+        vulkan_instance: pointer(sys::render::SDL_PROP_RENDERER_VULKAN_INSTANCE_POINTER),
+        /// The `VkSurfaceKHR` of the renderer.
+        vulkan_surface: number(sys::render::SDL_PROP_RENDERER_VULKAN_SURFACE_NUMBER),
+        /// The `VkPhysicalDevice` of the renderer.
+        vulkan_physical_device: pointer(sys::render::SDL_PROP_RENDERER_VULKAN_PHYSICAL_DEVICE_POINTER),
+        /// The `VkDevice` of the renderer.
+        vulkan_device: pointer(sys::render::SDL_PROP_RENDERER_VULKAN_DEVICE_POINTER),
+        /// The queue family index used for rendering.
+        vulkan_graphics_queue_family_index: number(sys::render::SDL_PROP_RENDERER_VULKAN_GRAPHICS_QUEUE_FAMILY_INDEX_NUMBER),
+        /// The queue family index used for presentation.
+        vulkan_present_queue_family_index: number(sys::render::SDL_PROP_RENDERER_VULKAN_PRESENT_QUEUE_FAMILY_INDEX_NUMBER),
+        /// The number of swapchain images, or potential frames in flight.
+        vulkan_swapchain_image_count: number(sys::render::SDL_PROP_RENDERER_VULKAN_SWAPCHAIN_IMAGE_COUNT_NUMBER),
+        /// The `SDL_GPUDevice` of the GPU renderer.
+        gpu_device: pointer(sys::render::SDL_PROP_RENDERER_GPU_DEVICE_POINTER),
+    }
+}
+
+impl RendererProperties {
+    /// The colorspace of the output.
+    pub fn output_colorspace(&self) -> Option<Colorspace> {
+        self.number(sys::render::SDL_PROP_RENDERER_OUTPUT_COLORSPACE_NUMBER)
+            .map(|colorspace| Colorspace(colorspace as u32))
+    }
+
+    /// The pixel formats supported for textures.
+    pub fn texture_formats(&self) -> Vec<PixelFormat> {
+        let mut formats = self.pointer(sys::render::SDL_PROP_RENDERER_TEXTURE_FORMATS_POINTER)
+            as *const sys::pixels::SDL_PixelFormat;
+        let mut result = Vec::new();
+        if formats.is_null() {
+            return result;
+        }
+        // The list is terminated by SDL_PIXELFORMAT_UNKNOWN.
+        unsafe {
+            while *formats != sys::pixels::SDL_PIXELFORMAT_UNKNOWN {
+                result.push(PixelFormat::from((*formats).0 as i64));
+                formats = formats.add(1);
+            }
+        }
+        result
+    }
+}
+
+typed_properties! {
+    /// The properties of a texture, see [`Texture::get_properties`].
+    pub struct TextureProperties {
+        /// The width of the texture in pixels.
+        width: number(sys::render::SDL_PROP_TEXTURE_WIDTH_NUMBER),
+        /// The height of the texture in pixels.
+        height: number(sys::render::SDL_PROP_TEXTURE_HEIGHT_NUMBER),
+        /// The value of SDR white in the linear colorspace, for HDR textures.
+        sdr_white_point: float(sys::render::SDL_PROP_TEXTURE_SDR_WHITE_POINT_FLOAT),
+        /// The maximum dynamic range, in terms of the SDR white point, for HDR textures.
+        hdr_headroom: float(sys::render::SDL_PROP_TEXTURE_HDR_HEADROOM_FLOAT),
+        /// The `ID3D11Texture2D` of the texture.
+        d3d11_texture: pointer(sys::render::SDL_PROP_TEXTURE_D3D11_TEXTURE_POINTER),
+        /// The `ID3D11Texture2D` of the U plane of a YUV texture.
+        d3d11_texture_u: pointer(sys::render::SDL_PROP_TEXTURE_D3D11_TEXTURE_U_POINTER),
+        /// The `ID3D11Texture2D` of the V plane of a YUV texture.
+        d3d11_texture_v: pointer(sys::render::SDL_PROP_TEXTURE_D3D11_TEXTURE_V_POINTER),
+        /// The `ID3D12Resource` of the texture.
+        d3d12_texture: pointer(sys::render::SDL_PROP_TEXTURE_D3D12_TEXTURE_POINTER),
+        /// The `ID3D12Resource` of the U plane of a YUV texture.
+        d3d12_texture_u: pointer(sys::render::SDL_PROP_TEXTURE_D3D12_TEXTURE_U_POINTER),
+        /// The `ID3D12Resource` of the V plane of a YUV texture.
+        d3d12_texture_v: pointer(sys::render::SDL_PROP_TEXTURE_D3D12_TEXTURE_V_POINTER),
+        /// The `GLuint` texture of the texture.
+        opengl_texture: number(sys::render::SDL_PROP_TEXTURE_OPENGL_TEXTURE_NUMBER),
+        /// The `GLuint` texture of the UV plane of a NV12 texture.
+        opengl_texture_uv: number(sys::render::SDL_PROP_TEXTURE_OPENGL_TEXTURE_UV_NUMBER),
+        /// The `GLuint` texture of the U plane of a YUV texture.
+        opengl_texture_u: number(sys::render::SDL_PROP_TEXTURE_OPENGL_TEXTURE_U_NUMBER),
+        /// The `GLuint` texture of the V plane of a YUV texture.
+        opengl_texture_v: number(sys::render::SDL_PROP_TEXTURE_OPENGL_TEXTURE_V_NUMBER),
+        /// The `GLenum` texture target, e.g. `GL_TEXTURE_2D`.
+        opengl_texture_target: number(sys::render::SDL_PROP_TEXTURE_OPENGL_TEXTURE_TARGET_NUMBER),
+        /// The texture coordinate width of the texture, from 0.0 to 1.0.
+        opengl_tex_w: float(sys::render::SDL_PROP_TEXTURE_OPENGL_TEX_W_FLOAT),
+        /// The texture coordinate height of the texture, from 0.0 to 1.0.
+        opengl_tex_h: float(sys::render::SDL_PROP_TEXTURE_OPENGL_TEX_H_FLOAT),
+        /// The `GLuint` texture of the texture.
+        opengles2_texture: number(sys::render::SDL_PROP_TEXTURE_OPENGLES2_TEXTURE_NUMBER),
+        /// The `GLuint` texture of the UV plane of a NV12 texture.
+        opengles2_texture_uv: number(sys::render::SDL_PROP_TEXTURE_OPENGLES2_TEXTURE_UV_NUMBER),
+        /// The `GLuint` texture of the U plane of a YUV texture.
+        opengles2_texture_u: number(sys::render::SDL_PROP_TEXTURE_OPENGLES2_TEXTURE_U_NUMBER),
+        /// The `GLuint` texture of the V plane of a YUV texture.
+        opengles2_texture_v: number(sys::render::SDL_PROP_TEXTURE_OPENGLES2_TEXTURE_V_NUMBER),
+        /// The `GLenum` texture target, e.g. `GL_TEXTURE_2D`.
+        opengles2_texture_target: number(sys::render::SDL_PROP_TEXTURE_OPENGLES2_TEXTURE_TARGET_NUMBER),
+        /// The `VkImage` of the texture.
+        vulkan_texture: number(sys::render::SDL_PROP_TEXTURE_VULKAN_TEXTURE_NUMBER),
+    }
+}
+
+impl TextureProperties {
+    /// The colorspace of the texture.
+    pub fn colorspace(&self) -> Option<Colorspace> {
+        self.number(sys::render::SDL_PROP_TEXTURE_COLORSPACE_NUMBER)
+            .map(|colorspace| Colorspace(colorspace as u32))
+    }
+
+    /// The pixel format of the texture.
+    pub fn format(&self) -> Option<PixelFormat> {
+        self.number(sys::render::SDL_PROP_TEXTURE_FORMAT_NUMBER)
+            .map(PixelFormat::from)
+    }
+
+    /// The access of the texture.
+    pub fn access(&self) -> Option<TextureAccess> {
+        self.number(sys::render::SDL_PROP_TEXTURE_ACCESS_NUMBER)
+            .map(TextureAccess::from)
+    }
+}
+
 struct InternalTexture {
     raw: *mut sys::render::SDL_Texture,
 }
@@ -2711,6 +2867,12 @@ impl InternalTexture {
 
 #[cfg(not(feature = "unsafe_textures"))]
 impl Texture<'_> {
+    /// Returns the properties of the texture, e.g. its colorspace and native handles.
+    #[doc(alias = "SDL_GetTextureProperties")]
+    pub fn get_properties(&self) -> Result<TextureProperties, PropertiesError> {
+        TextureProperties::from_ll(unsafe { SDL_GetTextureProperties(self.raw) })
+    }
+
     /// Gets the texture's internal properties.
     #[inline]
     pub fn query(&self) -> TextureQuery {
@@ -2946,6 +3108,12 @@ impl Texture<'_> {
 
 #[cfg(feature = "unsafe_textures")]
 impl Texture {
+    /// Returns the properties of the texture, e.g. its colorspace and native handles.
+    #[doc(alias = "SDL_GetTextureProperties")]
+    pub fn get_properties(&self) -> Result<TextureProperties, PropertiesError> {
+        TextureProperties::from_ll(unsafe { SDL_GetTextureProperties(self.raw) })
+    }
+
     /// Gets the texture's internal properties.
     #[inline]
     pub fn query(&self) -> TextureQuery {
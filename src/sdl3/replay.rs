@@ -0,0 +1,267 @@
+//! Event recording and replay
+//!
+//! An [`EventRecorder`] writes events, e.g. everything coming out of the
+//! [`EventPump`](crate::EventPump), to a stream. An [`EventPlayer`] reads them back and pushes
+//! them into the event queue again, either at the recorded pace or one step at a time, which
+//! makes it possible to reproduce a session exactly.
+//!
+//! Recordings are JSON lines: a header line followed by one [`RecordedEvent`] per line, written
+//! with the `serde` implementations of the events. This module requires the `serde` feature.
+//! Ids of windows and devices are replayed as recorded; they only match if the app creates
+//! them in the same order.
+//!
+//! # Example
+//! ```no_run
+//! use sdl3::replay::{EventPlayer, EventRecorder};
+//! use std::fs::File;
+//!
+//! let sdl_context = sdl3::init().unwrap();
+//! let mut event_pump = sdl_context.event_pump().unwrap();
+//!
+//! let mut recorder = EventRecorder::new(File::create("session.events").unwrap()).unwrap();
+//! for event in event_pump.poll_iter() {
+//!     recorder.record(&event).unwrap();
+//! }
+//! recorder.into_inner().unwrap();
+//!
+//! let events = sdl_context.event().unwrap();
+//! let mut player = EventPlayer::new(&events, File::open("session.events").unwrap()).unwrap();
+//! while !player.is_finished() {
+//!     player.update().unwrap();
+//!     for event in event_pump.poll_iter() {
+//!         // Handle the replayed events as usual.
+//!     }
+//! }
+//! ```
+
+use crate::event::Event;
+use crate::timer::ticks_ns;
+use crate::Error;
+use crate::EventSubsystem;
+use serde::{Deserialize, Serialize};
+use std::error;
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read, Write};
+
+const FORMAT: &str = "sdl3-events";
+const VERSION: u32 = 2;
+
+/// The first line of a recording.
+#[derive(Serialize, Deserialize)]
+struct Header {
+    format: String,
+    version: u32,
+}
+
+#[derive(Debug)]
+pub enum ReplayError {
+    Io(io::Error),
+    /// The data is not a recording, or was made with a different version.
+    InvalidFormat,
+    /// The event can't be recorded, e.g. a user event carrying pointers.
+    UnsupportedEvent(Box<Event>),
+    SdlError(Error),
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::ReplayError::*;
+
+        match *self {
+            Io(ref e) => write!(f, "I/O error: {}", e),
+            InvalidFormat => write!(f, "Invalid event recording"),
+            UnsupportedEvent(ref e) => write!(f, "Event can't be recorded: {:?}", e),
+            SdlError(ref e) => write!(f, "SDL error: {}", e),
+        }
+    }
+}
+
+impl error::Error for ReplayError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ReplayError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ReplayError {
+    fn from(e: io::Error) -> ReplayError {
+        ReplayError::Io(e)
+    }
+}
+
+/// An event together with the time it happened, relative to the first recorded event.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct RecordedEvent {
+    pub time_ns: u64,
+    pub event: Event,
+}
+
+fn write_line<W: Write, T: Serialize>(writer: &mut W, value: &T) -> Result<(), ReplayError> {
+    serde_json::to_writer(&mut *writer, value).map_err(io::Error::from)?;
+    writer.write_all(b"\n")?;
+    Ok(())
+}
+
+/// Writes events to a recording, see the [module documentation](self).
+pub struct EventRecorder<W: Write> {
+    writer: W,
+    first_timestamp: Option<u64>,
+}
+
+impl<W: Write> EventRecorder<W> {
+    /// Starts a recording by writing its header to `writer`.
+    pub fn new(mut writer: W) -> Result<EventRecorder<W>, ReplayError> {
+        let header = Header {
+            format: FORMAT.to_owned(),
+            version: VERSION,
+        };
+        write_line(&mut writer, &header)?;
+        Ok(EventRecorder {
+            writer,
+            first_timestamp: None,
+        })
+    }
+
+    /// Appends an event to the recording.
+    ///
+    /// Events that can't be pushed to the event queue can't be recorded, and neither can user
+    /// events with non-null data pointers, e.g. custom events, since the pointers would be
+    /// dangling on replay.
+    pub fn record(&mut self, event: &Event) -> Result<(), ReplayError> {
+        let unsupported = || ReplayError::UnsupportedEvent(Box::new(event.clone()));
+        match *event {
+            Event::User { data1, data2, .. } if !data1.is_null() || !data2.is_null() => {
+                return Err(unsupported());
+            }
+            _ if !event.has_raw_form() => return Err(unsupported()),
+            _ => {}
+        }
+
+        let timestamp = event.get_timestamp();
+        let first_timestamp = *self.first_timestamp.get_or_insert(timestamp);
+        let recorded = RecordedEvent {
+            time_ns: timestamp.saturating_sub(first_timestamp),
+            event: event.clone(),
+        };
+        write_line(&mut self.writer, &recorded)
+    }
+
+    /// Flushes the recording and returns the underlying writer.
+    pub fn into_inner(mut self) -> Result<W, ReplayError> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+/// Reads all events of a recording made with [`EventRecorder`].
+pub fn read_events<R: Read>(reader: R) -> Result<Vec<RecordedEvent>, ReplayError> {
+    let mut lines = BufReader::new(reader).lines();
+    let header: Header = match lines.next() {
+        Some(line) => serde_json::from_str(&line?).map_err(|_| ReplayError::InvalidFormat)?,
+        None => return Err(ReplayError::InvalidFormat),
+    };
+    if header.format != FORMAT || header.version != VERSION {
+        return Err(ReplayError::InvalidFormat);
+    }
+
+    let mut events = Vec::new();
+    for line in lines {
+        let line = line?;
+        if line.is_empty() {
+            continue;
+        }
+        events.push(serde_json::from_str(&line).map_err(|_| ReplayError::InvalidFormat)?);
+    }
+    Ok(events)
+}
+
+/// Pushes recorded events into the event queue, see the [module documentation](self).
+///
+/// Replayed events keep their recorded timestamps.
+pub struct EventPlayer {
+    subsystem: EventSubsystem,
+    events: Vec<RecordedEvent>,
+    next: usize,
+    start: Option<u64>,
+}
+
+impl EventPlayer {
+    /// Reads a recording to replay through `subsystem`.
+    pub fn new<R: Read>(subsystem: &EventSubsystem, reader: R) -> Result<EventPlayer, ReplayError> {
+        Ok(EventPlayer::from_events(subsystem, read_events(reader)?))
+    }
+
+    /// Replays `events`, which must be sorted by time.
+    pub fn from_events(subsystem: &EventSubsystem, events: Vec<RecordedEvent>) -> EventPlayer {
+        EventPlayer {
+            subsystem: subsystem.clone(),
+            events,
+            next: 0,
+            start: None,
+        }
+    }
+
+    /// All events of the recording.
+    pub fn events(&self) -> &[RecordedEvent] {
+        &self.events
+    }
+
+    /// The number of events pushed so far.
+    pub fn position(&self) -> usize {
+        self.next
+    }
+
+    /// Returns `true` if all events were pushed.
+    pub fn is_finished(&self) -> bool {
+        self.next >= self.events.len()
+    }
+
+    /// Starts over from the first event.
+    pub fn rewind(&mut self) {
+        self.next = 0;
+        self.start = None;
+    }
+
+    /// Pushes all events that are due at the recorded pace, and returns how many were pushed.
+    ///
+    /// The clock starts with the first call; call this once per frame before polling events.
+    pub fn update(&mut self) -> Result<usize, ReplayError> {
+        let now = ticks_ns();
+        let start = *self.start.get_or_insert(now);
+        self.advance_to(now - start)
+    }
+
+    /// Pushes all events recorded up to `time_ns` after the first event, and returns how many
+    /// were pushed.
+    ///
+    /// This replays independently of the real time, e.g. in lockstep with a fixed timestep.
+    pub fn advance_to(&mut self, time_ns: u64) -> Result<usize, ReplayError> {
+        let mut pushed = 0;
+        while self
+            .events
+            .get(self.next)
+            .is_some_and(|recorded| recorded.time_ns <= time_ns)
+        {
+            self.step()?;
+            pushed += 1;
+        }
+        Ok(pushed)
+    }
+
+    /// Pushes the next event regardless of its time, and returns it.
+    ///
+    /// Returns `None` if all events were pushed. An event that can't be pushed, e.g. because
+    /// the event filter dropped it, is skipped after returning the error.
+    pub fn step(&mut self) -> Result<Option<&RecordedEvent>, ReplayError> {
+        let Some(recorded) = self.events.get(self.next) else {
+            return Ok(None);
+        };
+        self.next += 1;
+        self.subsystem
+            .push_event(recorded.event.clone())
+            .map_err(ReplayError::SdlError)?;
+        Ok(Some(recorded))
+    }
+}
@@ -1,4 +1,4 @@
-use libc::c_char;
+use libc::{c_char, c_void};
 use std::cell::Cell;
 use std::error;
 use std::ffi::{CStr, CString, NulError};
@@ -112,6 +112,12 @@ impl Sdl {
         AudioSubsystem::new(self)
     }
 
+    /// Initializes the camera subsystem.
+    #[inline]
+    pub fn camera(&self) -> Result<CameraSubsystem, Error> {
+        CameraSubsystem::new(self)
+    }
+
     /// Initializes the event subsystem.
     #[inline]
     pub fn event(&self) -> Result<EventSubsystem, Error> {
@@ -358,6 +364,68 @@ pub fn get_platform() -> &'static str {
     }
 }
 
+/// Returns `true` if the current thread is the main thread.
+///
+/// The main thread is the one that first initialized SDL.
+#[inline]
+#[doc(alias = "SDL_IsMainThread")]
+pub fn is_main_thread() -> bool {
+    unsafe { sys::init::SDL_IsMainThread() }
+}
+
+/// Runs `callback` on the main thread during event processing.
+///
+/// If this is called on the main thread, or the events subsystem isn't initialized,
+/// `callback` runs immediately on the calling thread. Otherwise it is queued and runs the next
+/// time the main thread pumps events, e.g. through [`EventPump::poll_iter`]. If `wait` is
+/// `true`, this blocks until `callback` has finished.
+///
+/// Be careful not to wait for the main thread while it is itself waiting for the calling
+/// thread, as that deadlocks.
+///
+/// If SDL is shut down before a queued callback gets to run, SDL discards it without telling
+/// this library. The callback is then leaked: it is never called and its captured values are
+/// never dropped.
+///
+/// # Example
+/// ```no_run
+/// let sdl_context = sdl3::init().unwrap();
+/// let video_subsystem = sdl_context.video().unwrap();
+/// let mut event_pump = sdl_context.event_pump().unwrap();
+///
+/// std::thread::spawn(|| {
+///     // ... load assets ...
+///     sdl3::run_on_main_thread(|| println!("assets loaded"), false).unwrap();
+/// });
+///
+/// for event in event_pump.poll_iter() {
+///     // ...
+/// }
+/// ```
+#[doc(alias = "SDL_RunOnMainThread")]
+pub fn run_on_main_thread<F>(callback: F, wait: bool) -> Result<(), Error>
+where
+    F: FnOnce() + Send + 'static,
+{
+    unsafe extern "C" fn run_callback<F: FnOnce()>(userdata: *mut c_void) {
+        let callback = Box::from_raw(userdata as *mut F);
+        callback();
+    }
+
+    let userdata = Box::into_raw(Box::new(callback));
+    let result = unsafe {
+        sys::init::SDL_RunOnMainThread(Some(run_callback::<F>), userdata as *mut c_void, wait)
+    };
+
+    if result {
+        Ok(())
+    } else {
+        // SDL never calls the callback when it reports failure, so it is still ours to free.
+        drop(unsafe { Box::from_raw(userdata) });
+        Err(get_error())
+    }
+}
+
 /// Initializes the SDL library.
 /// This must be called before using any other SDL function.
 ///
@@ -73,6 +73,7 @@ impl SensorSubsystem {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SensorType {
     Unknown,
     Gyroscope,
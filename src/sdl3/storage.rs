@@ -0,0 +1,371 @@
+//! Storage Functions
+//!
+//! An abstraction over the places a game reads and writes its data. Title storage holds the
+//! read-only files that ship with the game, user storage holds per-user data such as save
+//! games, and file storage gives the same interface over an arbitrary directory, which is
+//! handy for tests and tools.
+//!
+//! Storage containers may not be usable right away, e.g. while a cloud sync is in progress;
+//! wait for [`Storage::is_ready`] before accessing them. Paths are always relative to the root
+//! of the container and use `/` as separator.
+//!
+//! # Example
+//! ```no_run
+//! use sdl3::storage::UserStorage;
+//!
+//! let storage = UserStorage::open("My Company", "My Game").unwrap();
+//! while !storage.is_ready() {
+//!     std::thread::sleep(std::time::Duration::from_millis(1));
+//! }
+//!
+//! storage.write_file("save.dat", b"level 3").unwrap();
+//! assert_eq!(storage.read_file("save.dat").unwrap(), b"level 3");
+//! ```
+
+use libc::{c_char, c_void};
+use std::ffi::{CStr, CString};
+use std::mem;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::ptr;
+use sys::filesystem::SDL_PathInfo;
+use sys::storage::SDL_Storage;
+
+use crate::filesystem::{
+    cstring_path, path_cstring, EnumerationResult, FileSystemError, GlobFlags, GlobResults,
+    PathInfo, PathType,
+};
+use crate::get_error;
+use crate::sys;
+
+/// A storage container, see the [module documentation](self).
+pub struct Storage {
+    raw: *mut SDL_Storage,
+}
+
+/// Opens a storage container for the given directory.
+///
+/// This is a plain read-write container without any of the special behavior of
+/// [`TitleStorage`] and [`UserStorage`].
+#[doc(alias = "SDL_OpenFileStorage")]
+pub fn open_file_storage(path: impl AsRef<Path>) -> Result<Storage, FileSystemError> {
+    path_cstring!(path);
+    let raw = unsafe { sys::storage::SDL_OpenFileStorage(path.as_ptr()) };
+    unsafe { Storage::from_ll(raw) }
+}
+
+impl Storage {
+    unsafe fn from_ll(raw: *mut SDL_Storage) -> Result<Storage, FileSystemError> {
+        if raw.is_null() {
+            Err(FileSystemError::SdlError(get_error()))
+        } else {
+            Ok(Storage { raw })
+        }
+    }
+
+    #[inline]
+    pub const fn raw(&self) -> *mut SDL_Storage {
+        self.raw
+    }
+
+    /// Closes the container, flushing any pending writes.
+    ///
+    /// Dropping a `Storage` closes it too, but ignores errors.
+    #[doc(alias = "SDL_CloseStorage")]
+    pub fn close(self) -> Result<(), FileSystemError> {
+        let raw = self.raw;
+        mem::forget(self);
+        if unsafe { sys::storage::SDL_CloseStorage(raw) } {
+            Ok(())
+        } else {
+            Err(FileSystemError::SdlError(get_error()))
+        }
+    }
+
+    /// Returns `true` if the container is ready to use.
+    #[doc(alias = "SDL_StorageReady")]
+    pub fn is_ready(&self) -> bool {
+        unsafe { sys::storage::SDL_StorageReady(self.raw) }
+    }
+
+    /// Returns the size of a file in bytes.
+    #[doc(alias = "SDL_GetStorageFileSize")]
+    pub fn file_size(&self, path: impl AsRef<Path>) -> Result<u64, FileSystemError> {
+        path_cstring!(path);
+        let mut length = 0;
+        if unsafe { sys::storage::SDL_GetStorageFileSize(self.raw, path.as_ptr(), &mut length) } {
+            Ok(length)
+        } else {
+            Err(FileSystemError::SdlError(get_error()))
+        }
+    }
+
+    /// Reads a whole file.
+    #[doc(alias = "SDL_ReadStorageFile")]
+    pub fn read_file(&self, path: impl AsRef<Path>) -> Result<Vec<u8>, FileSystemError> {
+        let length = self.file_size(&path)?;
+        path_cstring!(path);
+        let mut buf = vec![0u8; length as usize];
+        if unsafe {
+            sys::storage::SDL_ReadStorageFile(
+                self.raw,
+                path.as_ptr(),
+                buf.as_mut_ptr() as *mut c_void,
+                length,
+            )
+        } {
+            Ok(buf)
+        } else {
+            Err(FileSystemError::SdlError(get_error()))
+        }
+    }
+
+    /// Writes `data` to a file, replacing it if it already exists.
+    #[doc(alias = "SDL_WriteStorageFile")]
+    pub fn write_file(&self, path: impl AsRef<Path>, data: &[u8]) -> Result<(), FileSystemError> {
+        path_cstring!(path);
+        if unsafe {
+            sys::storage::SDL_WriteStorageFile(
+                self.raw,
+                path.as_ptr(),
+                data.as_ptr() as *const c_void,
+                data.len() as u64,
+            )
+        } {
+            Ok(())
+        } else {
+            Err(FileSystemError::SdlError(get_error()))
+        }
+    }
+
+    #[doc(alias = "SDL_CreateStorageDirectory")]
+    pub fn create_directory(&self, path: impl AsRef<Path>) -> Result<(), FileSystemError> {
+        path_cstring!(path);
+        if unsafe { sys::storage::SDL_CreateStorageDirectory(self.raw, path.as_ptr()) } {
+            Ok(())
+        } else {
+            Err(FileSystemError::SdlError(get_error()))
+        }
+    }
+
+    /// Calls `callback` for each entry of a directory, with the directory and the entry name.
+    ///
+    /// Pass an empty path to enumerate the root of the container.
+    #[doc(alias = "SDL_EnumerateStorageDirectory")]
+    pub fn enumerate_directory<F>(
+        &self,
+        path: impl AsRef<Path>,
+        mut callback: F,
+    ) -> Result<(), FileSystemError>
+    where
+        F: FnMut(&Path, &Path) -> EnumerationResult,
+    {
+        unsafe extern "C" fn c_enumerate<F>(
+            userdata: *mut c_void,
+            dirname: *const c_char,
+            fname: *const c_char,
+        ) -> EnumerationResult
+        where
+            F: FnMut(&Path, &Path) -> EnumerationResult,
+        {
+            let callback = &mut *(userdata as *mut F);
+
+            cstring_path!(dirname, return EnumerationResult::FAILURE);
+            cstring_path!(fname, return EnumerationResult::FAILURE);
+
+            callback(dirname, fname)
+        }
+
+        path_cstring!(path);
+        if unsafe {
+            sys::storage::SDL_EnumerateStorageDirectory(
+                self.raw,
+                path.as_ptr(),
+                Some(c_enumerate::<F>),
+                &mut callback as *mut F as *mut c_void,
+            )
+        } {
+            Ok(())
+        } else {
+            Err(FileSystemError::SdlError(get_error()))
+        }
+    }
+
+    /// Lists the entries of a directory, recursively, that match `pattern`.
+    ///
+    /// The results are owned and stay valid after the container is closed.
+    #[doc(alias = "SDL_GlobStorageDirectory")]
+    pub fn glob_directory(
+        &self,
+        path: impl AsRef<Path>,
+        pattern: Option<&str>,
+        flags: GlobFlags,
+    ) -> Result<GlobResults<'static>, FileSystemError> {
+        path_cstring!(path);
+        let pattern = match pattern {
+            Some(pattern) => match CString::new(pattern) {
+                Ok(pattern) => Some(pattern),
+                Err(error) => return Err(FileSystemError::NulError(error)),
+            },
+            None => None,
+        };
+        let pattern_ptr = pattern.as_ref().map_or(ptr::null(), |pat| pat.as_ptr());
+        let mut count = 0;
+
+        let results = unsafe {
+            let paths = sys::storage::SDL_GlobStorageDirectory(
+                self.raw,
+                path.as_ptr(),
+                pattern_ptr,
+                flags.bits(),
+                &mut count,
+            );
+            if paths.is_null() {
+                return Err(FileSystemError::SdlError(get_error()));
+            }
+            GlobResults::new(paths, count as isize)
+        };
+        Ok(results)
+    }
+
+    /// Removes a file or an empty directory.
+    #[doc(alias = "SDL_RemoveStoragePath")]
+    pub fn remove_path(&self, path: impl AsRef<Path>) -> Result<(), FileSystemError> {
+        path_cstring!(path);
+        if unsafe { sys::storage::SDL_RemoveStoragePath(self.raw, path.as_ptr()) } {
+            Ok(())
+        } else {
+            Err(FileSystemError::SdlError(get_error()))
+        }
+    }
+
+    #[doc(alias = "SDL_RenameStoragePath")]
+    pub fn rename_path(
+        &self,
+        old_path: impl AsRef<Path>,
+        new_path: impl AsRef<Path>,
+    ) -> Result<(), FileSystemError> {
+        path_cstring!(old_path);
+        path_cstring!(new_path);
+        if unsafe {
+            sys::storage::SDL_RenameStoragePath(self.raw, old_path.as_ptr(), new_path.as_ptr())
+        } {
+            Ok(())
+        } else {
+            Err(FileSystemError::SdlError(get_error()))
+        }
+    }
+
+    #[doc(alias = "SDL_CopyStorageFile")]
+    pub fn copy_file(
+        &self,
+        old_path: impl AsRef<Path>,
+        new_path: impl AsRef<Path>,
+    ) -> Result<(), FileSystemError> {
+        path_cstring!(old_path);
+        path_cstring!(new_path);
+        if unsafe {
+            sys::storage::SDL_CopyStorageFile(self.raw, old_path.as_ptr(), new_path.as_ptr())
+        } {
+            Ok(())
+        } else {
+            Err(FileSystemError::SdlError(get_error()))
+        }
+    }
+
+    #[doc(alias = "SDL_GetStoragePathInfo")]
+    pub fn path_info(&self, path: impl AsRef<Path>) -> Result<PathInfo, FileSystemError> {
+        let mut info = SDL_PathInfo {
+            r#type: PathType::NONE,
+            size: 0,
+            create_time: 0,
+            modify_time: 0,
+            access_time: 0,
+        };
+        path_cstring!(path);
+        if unsafe { sys::storage::SDL_GetStoragePathInfo(self.raw, path.as_ptr(), &mut info) } {
+            Ok(PathInfo::from_ll(info))
+        } else {
+            Err(FileSystemError::SdlError(get_error()))
+        }
+    }
+
+    /// Returns the remaining space in the container, in bytes.
+    #[doc(alias = "SDL_GetStorageSpaceRemaining")]
+    pub fn space_remaining(&self) -> u64 {
+        unsafe { sys::storage::SDL_GetStorageSpaceRemaining(self.raw) }
+    }
+}
+
+impl Drop for Storage {
+    #[doc(alias = "SDL_CloseStorage")]
+    fn drop(&mut self) {
+        unsafe {
+            sys::storage::SDL_CloseStorage(self.raw);
+        }
+    }
+}
+
+/// The read-only storage container holding the game's own files.
+pub struct TitleStorage(Storage);
+
+impl TitleStorage {
+    /// Opens the title storage container.
+    ///
+    /// `override_path` replaces the platform's default location, which is usually the
+    /// directory the executable lives in.
+    #[doc(alias = "SDL_OpenTitleStorage")]
+    pub fn open(override_path: Option<&Path>) -> Result<TitleStorage, FileSystemError> {
+        let raw = match override_path {
+            Some(path) => {
+                let Some(path_str) = path.to_str() else {
+                    return Err(FileSystemError::InvalidPathError(path.to_owned()));
+                };
+                let path = CString::new(path_str).map_err(FileSystemError::NulError)?;
+                unsafe { sys::storage::SDL_OpenTitleStorage(path.as_ptr(), 0) }
+            }
+            None => unsafe { sys::storage::SDL_OpenTitleStorage(ptr::null(), 0) },
+        };
+        unsafe { Storage::from_ll(raw) }.map(TitleStorage)
+    }
+
+    pub fn into_inner(self) -> Storage {
+        self.0
+    }
+}
+
+impl Deref for TitleStorage {
+    type Target = Storage;
+
+    #[inline]
+    fn deref(&self) -> &Storage {
+        &self.0
+    }
+}
+
+/// The read-write storage container for one user's data of an application.
+pub struct UserStorage(Storage);
+
+impl UserStorage {
+    /// Opens the user storage container of the application `app` made by `org`.
+    #[doc(alias = "SDL_OpenUserStorage")]
+    pub fn open(org: &str, app: &str) -> Result<UserStorage, FileSystemError> {
+        let org = CString::new(org).map_err(FileSystemError::NulError)?;
+        let app = CString::new(app).map_err(FileSystemError::NulError)?;
+        let raw = unsafe { sys::storage::SDL_OpenUserStorage(org.as_ptr(), app.as_ptr(), 0) };
+        unsafe { Storage::from_ll(raw) }.map(UserStorage)
+    }
+
+    pub fn into_inner(self) -> Storage {
+        self.0
+    }
+}
+
+impl Deref for UserStorage {
+    type Target = Storage;
+
+    #[inline]
+    fn deref(&self) -> &Storage {
+        &self.0
+    }
+}
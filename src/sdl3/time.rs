@@ -0,0 +1,242 @@
+//! Wall-clock date and time
+//!
+//! Unlike the monotonic ticks in [`timer`](crate::timer), a [`Time`] is a point in real time:
+//! nanoseconds since the Unix epoch (Jan 1, 1970 UTC). It can be broken down into a calendar
+//! [`DateTime`] in UTC or the local time zone, and back.
+//!
+//! # Example
+//! ```no_run
+//! use sdl3::time::{current_time, DateTime};
+//!
+//! let now = DateTime::from_time(current_time().unwrap(), true).unwrap();
+//! println!(
+//!     "{:04}-{:02}-{:02} {:02}:{:02}, day {} of the year",
+//!     now.year,
+//!     now.month,
+//!     now.day,
+//!     now.hour,
+//!     now.minute,
+//!     now.day_of_year().unwrap()
+//! );
+//! ```
+
+use crate::get_error;
+use crate::sys;
+use crate::Error;
+use libc::c_int;
+use sys::time::{SDL_DateFormat, SDL_DateTime, SDL_TimeFormat};
+
+/// Nanoseconds since the Unix epoch (Jan 1, 1970 UTC), negative for earlier times.
+pub type Time = sys::stdinc::SDL_Time;
+
+/// Returns the current wall-clock time.
+#[doc(alias = "SDL_GetCurrentTime")]
+pub fn current_time() -> Result<Time, Error> {
+    let mut time: Time = 0;
+    if unsafe { sys::time::SDL_GetCurrentTime(&mut time) } {
+        Ok(time)
+    } else {
+        Err(get_error())
+    }
+}
+
+/// Converts a [`Time`] to a Windows `FILETIME`, as `(low, high)` parts.
+#[doc(alias = "SDL_TimeToWindows")]
+pub fn time_to_windows(time: Time) -> (u32, u32) {
+    let mut low = 0;
+    let mut high = 0;
+    unsafe { sys::time::SDL_TimeToWindows(time, &mut low, &mut high) };
+    (low, high)
+}
+
+/// Converts a Windows `FILETIME`, given as `(low, high)` parts, to a [`Time`].
+#[doc(alias = "SDL_TimeFromWindows")]
+pub fn time_from_windows(low: u32, high: u32) -> Time {
+    unsafe { sys::time::SDL_TimeFromWindows(low, high) }
+}
+
+/// A calendar date and time of day.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct DateTime {
+    pub year: i32,
+    /// Month of the year, 1 to 12.
+    pub month: u8,
+    /// Day of the month, 1 to 31.
+    pub day: u8,
+    /// Hour of the day, 0 to 23.
+    pub hour: u8,
+    /// 0 to 59.
+    pub minute: u8,
+    /// 0 to 60, to allow for leap seconds.
+    pub second: u8,
+    /// 0 to 999999999.
+    pub nanosecond: u32,
+    /// Day of the week, 0 to 6 with 0 being Sunday.
+    ///
+    /// Ignored when converting to a [`Time`].
+    pub day_of_week: u8,
+    /// Offset from UTC in seconds, positive east of Greenwich.
+    pub utc_offset: i32,
+}
+
+impl DateTime {
+    pub fn from_ll(raw: SDL_DateTime) -> DateTime {
+        DateTime {
+            year: raw.year,
+            month: raw.month as u8,
+            day: raw.day as u8,
+            hour: raw.hour as u8,
+            minute: raw.minute as u8,
+            second: raw.second as u8,
+            nanosecond: raw.nanosecond as u32,
+            day_of_week: raw.day_of_week as u8,
+            utc_offset: raw.utc_offset,
+        }
+    }
+
+    pub fn to_ll(self) -> SDL_DateTime {
+        SDL_DateTime {
+            year: self.year,
+            month: self.month as c_int,
+            day: self.day as c_int,
+            hour: self.hour as c_int,
+            minute: self.minute as c_int,
+            second: self.second as c_int,
+            nanosecond: self.nanosecond as c_int,
+            day_of_week: self.day_of_week as c_int,
+            utc_offset: self.utc_offset,
+        }
+    }
+
+    /// Breaks `time` down into a calendar date, in the local time zone if `local` is `true` and
+    /// in UTC otherwise.
+    #[doc(alias = "SDL_TimeToDateTime")]
+    pub fn from_time(time: Time, local: bool) -> Result<DateTime, Error> {
+        let mut raw = SDL_DateTime::default();
+        if unsafe { sys::time::SDL_TimeToDateTime(time, &mut raw, local) } {
+            Ok(DateTime::from_ll(raw))
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Converts the date back to a [`Time`], taking `utc_offset` into account.
+    #[doc(alias = "SDL_DateTimeToTime")]
+    pub fn to_time(&self) -> Result<Time, Error> {
+        let raw = self.to_ll();
+        let mut time: Time = 0;
+        if unsafe { sys::time::SDL_DateTimeToTime(&raw, &mut time) } {
+            Ok(time)
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Returns the day of the year of this date, see [`day_of_year`].
+    pub fn day_of_year(&self) -> Result<u16, Error> {
+        day_of_year(self.year, self.month, self.day)
+    }
+}
+
+/// Returns the number of days in `month` (1 to 12) of `year`.
+#[doc(alias = "SDL_GetDaysInMonth")]
+pub fn days_in_month(year: i32, month: u8) -> Result<u8, Error> {
+    let result = unsafe { sys::time::SDL_GetDaysInMonth(year, month as c_int) };
+    if result < 0 {
+        Err(get_error())
+    } else {
+        Ok(result as u8)
+    }
+}
+
+/// Returns the day of the year of a date, 0 to 365 with 0 being Jan 1.
+#[doc(alias = "SDL_GetDayOfYear")]
+pub fn day_of_year(year: i32, month: u8, day: u8) -> Result<u16, Error> {
+    let result = unsafe { sys::time::SDL_GetDayOfYear(year, month as c_int, day as c_int) };
+    if result < 0 {
+        Err(get_error())
+    } else {
+        Ok(result as u16)
+    }
+}
+
+/// Returns the day of the week of a date, 0 to 6 with 0 being Sunday.
+#[doc(alias = "SDL_GetDayOfWeek")]
+pub fn day_of_week(year: i32, month: u8, day: u8) -> Result<u8, Error> {
+    let result = unsafe { sys::time::SDL_GetDayOfWeek(year, month as c_int, day as c_int) };
+    if result < 0 {
+        Err(get_error())
+    } else {
+        Ok(result as u8)
+    }
+}
+
+/// The order in which the user prefers dates to be written.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum DateFormat {
+    /// Year/Month/Day
+    YearMonthDay,
+    /// Day/Month/Year
+    DayMonthYear,
+    /// Month/Day/Year
+    MonthDayYear,
+}
+
+impl DateFormat {
+    pub fn from_ll(raw: SDL_DateFormat) -> DateFormat {
+        match raw {
+            SDL_DateFormat::DDMMYYYY => DateFormat::DayMonthYear,
+            SDL_DateFormat::MMDDYYYY => DateFormat::MonthDayYear,
+            _ => DateFormat::YearMonthDay,
+        }
+    }
+
+    pub fn to_ll(self) -> SDL_DateFormat {
+        match self {
+            DateFormat::YearMonthDay => SDL_DateFormat::YYYYMMDD,
+            DateFormat::DayMonthYear => SDL_DateFormat::DDMMYYYY,
+            DateFormat::MonthDayYear => SDL_DateFormat::MMDDYYYY,
+        }
+    }
+}
+
+/// Whether the user prefers a 24 or 12 hour clock.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum TimeFormat {
+    TwentyFourHour,
+    TwelveHour,
+}
+
+impl TimeFormat {
+    pub fn from_ll(raw: SDL_TimeFormat) -> TimeFormat {
+        match raw {
+            SDL_TimeFormat::_12HR => TimeFormat::TwelveHour,
+            _ => TimeFormat::TwentyFourHour,
+        }
+    }
+
+    pub fn to_ll(self) -> SDL_TimeFormat {
+        match self {
+            TimeFormat::TwentyFourHour => SDL_TimeFormat::_24HR,
+            TimeFormat::TwelveHour => SDL_TimeFormat::_12HR,
+        }
+    }
+}
+
+/// Returns the user's preferred date and time formats.
+///
+/// These may change while the app is running, e.g. on
+/// [`Event::LocaleChanged`](crate::event::Event::LocaleChanged).
+#[doc(alias = "SDL_GetDateTimeLocalePreferences")]
+pub fn date_time_locale_preferences() -> Result<(DateFormat, TimeFormat), Error> {
+    let mut date_format = SDL_DateFormat::YYYYMMDD;
+    let mut time_format = SDL_TimeFormat::_24HR;
+    if unsafe { sys::time::SDL_GetDateTimeLocalePreferences(&mut date_format, &mut time_format) } {
+        Ok((
+            DateFormat::from_ll(date_format),
+            TimeFormat::from_ll(time_format),
+        ))
+    } else {
+        Err(get_error())
+    }
+}
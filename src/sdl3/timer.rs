@@ -29,6 +29,28 @@ pub fn add_timer(delay: u32, callback: TimerCallback) -> Timer {
     }
 }
 
+/// Like [`add_timer`], but with the delay in nanoseconds.
+///
+/// The callback returns the next interval in nanoseconds, or 0 to cancel the timer.
+#[must_use = "if unused the Timer will be dropped immediately"]
+#[doc(alias = "SDL_AddTimerNS")]
+pub fn add_timer_ns(delay: u64, callback: TimerCallbackNs) -> Timer<TimerCallbackNs> {
+    unsafe {
+        let callback_ptr = Box::into_raw(Box::new(callback));
+
+        let timer_id = sys::timer::SDL_AddTimerNS(
+            delay,
+            Some(c_timer_callback_ns),
+            callback_ptr as *mut c_void,
+        );
+
+        Timer {
+            callback: Some(NonNull::new(callback_ptr).unwrap()),
+            raw: timer_id,
+        }
+    }
+}
+
 /// Gets the number of milliseconds elapsed since the timer subsystem was initialized.
 ///
 /// It's recommended to use another library for timekeeping, such as `time`.
@@ -37,6 +59,12 @@ pub fn ticks() -> u64 {
     unsafe { sys::timer::SDL_GetTicks() }
 }
 
+/// Gets the number of nanoseconds elapsed since the timer subsystem was initialized.
+#[doc(alias = "SDL_GetTicksNS")]
+pub fn ticks_ns() -> u64 {
+    unsafe { sys::timer::SDL_GetTicksNS() }
+}
+
 /// Sleeps the current thread for the specified amount of milliseconds.
 ///
 /// It's recommended to use `std::thread::sleep()` instead.
@@ -45,6 +73,24 @@ pub fn delay(ms: u32) {
     unsafe { sys::timer::SDL_Delay(ms) }
 }
 
+/// Sleeps the current thread for the specified amount of nanoseconds.
+///
+/// Like [`delay`], this may sleep longer than requested due to OS scheduling.
+#[doc(alias = "SDL_DelayNS")]
+pub fn delay_ns(ns: u64) {
+    unsafe { sys::timer::SDL_DelayNS(ns) }
+}
+
+/// Sleeps the current thread for the specified amount of nanoseconds, as close to the
+/// requested time as possible.
+///
+/// SDL sleeps for most of the time and busy-waits for the rest, trading some CPU time for
+/// accuracy.
+#[doc(alias = "SDL_DelayPrecise")]
+pub fn delay_precise(ns: u64) {
+    unsafe { sys::timer::SDL_DelayPrecise(ns) }
+}
+
 #[doc(alias = "SDL_GetPerformanceCounter")]
 pub fn performance_counter() -> u64 {
     unsafe { sys::timer::SDL_GetPerformanceCounter() }
@@ -58,20 +104,24 @@ pub fn performance_frequency() -> u64 {
 /// Type alias for the timer callback function.
 pub type TimerCallback = Box<dyn FnMut() -> u32 + Send + 'static>;
 
-pub struct Timer {
-    callback: Option<NonNull<TimerCallback>>,
+/// Type alias for the callback function of nanosecond timers.
+pub type TimerCallbackNs = Box<dyn FnMut() -> u64 + Send + 'static>;
+
+/// A running timer, see [`add_timer`] and [`add_timer_ns`].
+pub struct Timer<C = TimerCallback> {
+    callback: Option<NonNull<C>>,
     raw: sys::timer::SDL_TimerID,
 }
 
-impl Timer {
+impl<C> Timer<C> {
     /// Returns the closure as a trait-object and cancels the timer
     /// by consuming it.
-    pub fn into_inner(mut self) -> TimerCallback {
+    pub fn into_inner(mut self) -> C {
         unsafe {
             sys::timer::SDL_RemoveTimer(self.raw);
             if let Some(callback_ptr) = self.callback.take() {
                 // Reconstruct the Box from the raw pointer.
-                Box::from_raw(callback_ptr.as_ptr())
+                *Box::from_raw(callback_ptr.as_ptr())
             } else {
                 panic!("Timer callback already taken");
             }
@@ -79,7 +129,7 @@ impl Timer {
     }
 }
 
-impl Drop for Timer {
+impl<C> Drop for Timer<C> {
     #[inline]
     #[doc(alias = "SDL_RemoveTimer")]
     fn drop(&mut self) {
@@ -102,13 +152,184 @@ extern "C" fn c_timer_callback(
     unsafe { (*callback_ptr)() }
 }
 
+extern "C" fn c_timer_callback_ns(
+    userdata: *mut c_void,
+    _timer_id: sys::timer::SDL_TimerID,
+    _interval: u64,
+) -> u64 {
+    let callback_ptr = userdata as *mut TimerCallbackNs;
+    unsafe { (*callback_ptr)() }
+}
+
+const NS_PER_SECOND: u64 = sys::timer::SDL_NS_PER_SECOND as u64;
+
+/// Runs game logic at a fixed rate, independent of the frame rate.
+///
+/// Each frame, call [`advance`](Self::advance) to find out how many fixed-size update steps
+/// to run, then render with [`alpha`](Self::alpha) to interpolate between the last two
+/// update states.
+///
+/// # Example
+/// ```no_run
+/// use sdl3::timer::{FixedTimestep, FrameLimiter};
+///
+/// let mut timestep = FixedTimestep::new(50);
+/// let mut limiter = FrameLimiter::new(60);
+/// loop {
+///     for _ in 0..timestep.advance() {
+///         // Update the game state by `timestep.step_seconds()`.
+///     }
+///     // Render, blending the previous and current state by `timestep.alpha()`.
+///     limiter.wait();
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct FixedTimestep {
+    step_ns: u64,
+    max_frame_ns: u64,
+    accumulator: u64,
+    last: Option<u64>,
+}
+
+impl FixedTimestep {
+    /// Creates a timestep running `updates_per_second` updates per second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `updates_per_second` is 0.
+    pub fn new(updates_per_second: u32) -> FixedTimestep {
+        assert!(updates_per_second > 0, "updates_per_second must not be 0");
+        FixedTimestep::from_step_ns(NS_PER_SECOND / updates_per_second as u64)
+    }
+
+    /// Creates a timestep with steps of `step_ns` nanoseconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step_ns` is 0.
+    pub fn from_step_ns(step_ns: u64) -> FixedTimestep {
+        assert!(step_ns > 0, "step_ns must not be 0");
+        FixedTimestep {
+            step_ns,
+            max_frame_ns: NS_PER_SECOND / 4,
+            accumulator: 0,
+            last: None,
+        }
+    }
+
+    /// Sets the longest frame time that is caught up on, 250 ms by default.
+    ///
+    /// Longer frames, e.g. after the window was dragged or the process was suspended, are
+    /// clamped to this so the game doesn't fall further behind trying to catch up.
+    pub fn set_max_frame_time_ns(&mut self, max_frame_ns: u64) -> &mut FixedTimestep {
+        self.max_frame_ns = max_frame_ns;
+        self
+    }
+
+    /// The length of one update step in nanoseconds.
+    #[inline]
+    pub fn step_ns(&self) -> u64 {
+        self.step_ns
+    }
+
+    /// The length of one update step in seconds.
+    #[inline]
+    pub fn step_seconds(&self) -> f64 {
+        self.step_ns as f64 / NS_PER_SECOND as f64
+    }
+
+    /// Accumulates the real time passed since the last call and returns the number of update
+    /// steps to run this frame.
+    ///
+    /// The first call only starts the clock and returns 0.
+    pub fn advance(&mut self) -> u32 {
+        self.advance_to(ticks_ns())
+    }
+
+    /// Like [`advance`](Self::advance), but with the current time given in nanoseconds, e.g.
+    /// from [`ticks_ns`] or a replay.
+    pub fn advance_to(&mut self, now_ns: u64) -> u32 {
+        let elapsed = match self.last {
+            Some(last) => now_ns.saturating_sub(last),
+            None => 0,
+        };
+        self.last = Some(now_ns);
+        self.accumulator += elapsed.min(self.max_frame_ns);
+
+        let steps = self.accumulator / self.step_ns;
+        self.accumulator %= self.step_ns;
+        steps as u32
+    }
+
+    /// How far the time is between the last and the next update step, from 0.0 to 1.0.
+    #[inline]
+    pub fn alpha(&self) -> f64 {
+        self.accumulator as f64 / self.step_ns as f64
+    }
+
+    /// Forgets accumulated time, e.g. after loading a level.
+    pub fn reset(&mut self) {
+        self.accumulator = 0;
+        self.last = None;
+    }
+}
+
+/// Limits a loop to a target rate by sleeping precisely until the next frame is due.
+///
+/// Unlike sleeping for a fixed duration after each frame, the time spent on the frame
+/// itself is taken into account.
+#[derive(Clone, Debug)]
+pub struct FrameLimiter {
+    frame_ns: u64,
+    next: Option<u64>,
+}
+
+impl FrameLimiter {
+    /// Creates a limiter for `frames_per_second` frames per second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frames_per_second` is 0.
+    pub fn new(frames_per_second: u32) -> FrameLimiter {
+        assert!(frames_per_second > 0, "frames_per_second must not be 0");
+        FrameLimiter {
+            frame_ns: NS_PER_SECOND / frames_per_second as u64,
+            next: None,
+        }
+    }
+
+    /// The target length of one frame in nanoseconds.
+    #[inline]
+    pub fn frame_ns(&self) -> u64 {
+        self.frame_ns
+    }
+
+    /// Sleeps until the next frame is due.
+    ///
+    /// If the loop fell behind by more than a frame, the schedule restarts from now instead
+    /// of rushing through the missed frames.
+    #[doc(alias = "SDL_DelayPrecise")]
+    pub fn wait(&mut self) {
+        let now = ticks_ns();
+        let next = match self.next {
+            Some(next) if next > now => {
+                delay_precise(next - now);
+                next
+            }
+            Some(next) if now - next < self.frame_ns => next,
+            _ => now,
+        };
+        self.next = Some(next + self.frame_ns);
+    }
+}
+
 #[cfg(not(target_os = "macos"))]
 #[cfg(test)]
 mod test {
     use std::sync::{Arc, Mutex};
     use std::time::Duration;
 
-    use crate::timer::add_timer;
+    use crate::timer::{add_timer, add_timer_ns, FixedTimestep};
 
     #[test]
     fn test_timer_runs_multiple_times() {
@@ -158,6 +379,41 @@ mod test {
         assert_eq!(*flag, true);
     }
 
+    #[test]
+    fn test_timer_ns_runs_at_least_once() {
+        let _sdl_context = crate::sdl::init().unwrap();
+
+        let local_flag = Arc::new(Mutex::new(false));
+        let timer_flag = local_flag.clone();
+
+        let _timer = add_timer_ns(
+            20_000_000,
+            Box::new(move || {
+                let mut flag = timer_flag.lock().unwrap();
+                *flag = true;
+                0
+            }),
+        );
+
+        std::thread::sleep(Duration::from_millis(50));
+        let flag = local_flag.lock().unwrap();
+        assert_eq!(*flag, true);
+    }
+
+    #[test]
+    fn test_fixed_timestep() {
+        let mut timestep = FixedTimestep::from_step_ns(10);
+        assert_eq!(timestep.advance_to(1000), 0);
+        assert_eq!(timestep.advance_to(1025), 2);
+        assert_eq!(timestep.alpha(), 0.5);
+        assert_eq!(timestep.advance_to(1030), 1);
+        assert_eq!(timestep.alpha(), 0.0);
+
+        // Long frames are clamped.
+        timestep.set_max_frame_time_ns(100);
+        assert_eq!(timestep.advance_to(5000), 10);
+    }
+
     #[test]
     fn test_timer_can_be_recreated() {
         let sdl_context = crate::sdl::init().unwrap();
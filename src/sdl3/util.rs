@@ -1,3 +1,28 @@
+use std::sync::{Arc, RwLock};
+
 pub fn option_to_ptr<T>(opt: Option<&T>) -> *const T {
     opt.map_or(std::ptr::null(), |v| v as *const _)
 }
+
+/// A process-wide closure that SDL calls through a C callback, e.g. a log output function.
+pub(crate) struct ClosureSlot<F: ?Sized>(RwLock<Option<Arc<F>>>);
+
+impl<F: ?Sized> ClosureSlot<F> {
+    pub const fn new() -> Self {
+        ClosureSlot(RwLock::new(None))
+    }
+
+    /// Returns the closure, if any.
+    ///
+    /// The closure is shared rather than borrowed, so no lock is held while it runs. It may
+    /// therefore replace itself without deadlocking.
+    pub fn get(&self) -> Option<Arc<F>> {
+        self.0.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Replaces the closure and returns the previous one.
+    pub fn replace(&self, closure: Option<Arc<F>>) -> Option<Arc<F>> {
+        let mut current = self.0.write().unwrap_or_else(|e| e.into_inner());
+        std::mem::replace(&mut *current, closure)
+    }
+}
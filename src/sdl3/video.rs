@@ -1,7 +1,7 @@
 use crate::common::{validate_int, IntegerOrSdlError};
 use crate::get_error;
 use crate::pixels::PixelFormat;
-use crate::properties::{Properties, PropertiesError};
+use crate::properties::PropertiesError;
 use crate::rect::Rect;
 use crate::render::{create_renderer, WindowCanvas};
 use crate::surface::SurfaceRef;
@@ -602,6 +602,7 @@ impl From<i32> for SwapInterval {
 /// Represents orientation of a display.
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 #[repr(i32)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Orientation {
     /// The display orientation can’t be determined
     Unknown = sys::video::SDL_DisplayOrientation::UNKNOWN.0,
@@ -706,7 +707,99 @@ pub enum SystemTheme {
     Dark,
 }
 
+typed_properties! {
+    /// The properties of a [`Display`], see [`Display::get_properties`].
+    pub struct DisplayProperties {
+        /// Whether the display has HDR headroom above the SDR white point. This is for
+        /// informational purposes only, it may not be up to date.
+        hdr_enabled: boolean(sys::video::SDL_PROP_DISPLAY_HDR_ENABLED_BOOLEAN),
+        /// The panel orientation in degrees with the KMS/DRM driver, e.g. 90 for a display
+        /// mounted sideways.
+        kmsdrm_panel_orientation: number(sys::video::SDL_PROP_DISPLAY_KMSDRM_PANEL_ORIENTATION_NUMBER),
+    }
+}
+
+typed_properties! {
+    /// The properties of a [`Window`], see [`Window::get_properties`].
+    ///
+    /// Most of them are native handles that are only set with the matching video driver.
+    pub struct WindowProperties {
+        /// The `SDL_Surface` used as the shape of a transparent window.
+        shape: pointer(sys::video::SDL_PROP_WINDOW_SHAPE_POINTER),
+        /// Whether the window has HDR headroom above the SDR white point.
+        hdr_enabled: boolean(sys::video::SDL_PROP_WINDOW_HDR_ENABLED_BOOLEAN),
+        /// The value of SDR white in the linear colorspace.
+        sdr_white_level: float(sys::video::SDL_PROP_WINDOW_SDR_WHITE_LEVEL_FLOAT),
+        /// The additional high dynamic range that can be displayed, in terms of the SDR white
+        /// point.
+        hdr_headroom: float(sys::video::SDL_PROP_WINDOW_HDR_HEADROOM_FLOAT),
+        /// The `ANativeWindow` of the window.
+        android_window: pointer(sys::video::SDL_PROP_WINDOW_ANDROID_WINDOW_POINTER),
+        /// The `EGLSurface` of the window.
+        android_surface: pointer(sys::video::SDL_PROP_WINDOW_ANDROID_SURFACE_POINTER),
+        /// The `UIWindow` of the window.
+        uikit_window: pointer(sys::video::SDL_PROP_WINDOW_UIKIT_WINDOW_POINTER),
+        /// The tag of the Metal view of the window.
+        uikit_metal_view_tag: number(sys::video::SDL_PROP_WINDOW_UIKIT_METAL_VIEW_TAG_NUMBER),
+        /// The OpenGL framebuffer object of the window.
+        uikit_opengl_framebuffer: number(sys::video::SDL_PROP_WINDOW_UIKIT_OPENGL_FRAMEBUFFER_NUMBER),
+        /// The OpenGL renderbuffer object of the window.
+        uikit_opengl_renderbuffer: number(sys::video::SDL_PROP_WINDOW_UIKIT_OPENGL_RENDERBUFFER_NUMBER),
+        /// The OpenGL resolve framebuffer object of the window.
+        uikit_opengl_resolve_framebuffer: number(sys::video::SDL_PROP_WINDOW_UIKIT_OPENGL_RESOLVE_FRAMEBUFFER_NUMBER),
+        /// The index of the KMS/DRM device of the window.
+        kmsdrm_device_index: number(sys::video::SDL_PROP_WINDOW_KMSDRM_DEVICE_INDEX_NUMBER),
+        /// The DRM file descriptor of the window.
+        kmsdrm_drm_fd: number(sys::video::SDL_PROP_WINDOW_KMSDRM_DRM_FD_NUMBER),
+        /// The GBM device of the window.
+        kmsdrm_gbm_device: pointer(sys::video::SDL_PROP_WINDOW_KMSDRM_GBM_DEVICE_POINTER),
+        /// The `NSWindow` of the window.
+        cocoa_window: pointer(sys::video::SDL_PROP_WINDOW_COCOA_WINDOW_POINTER),
+        /// The tag of the Metal view of the window.
+        cocoa_metal_view_tag: number(sys::video::SDL_PROP_WINDOW_COCOA_METAL_VIEW_TAG_NUMBER),
+        /// The OpenVR overlay handle of the window.
+        openvr_overlay_id: number(sys::video::SDL_PROP_WINDOW_OPENVR_OVERLAY_ID),
+        /// The `EGLNativeDisplayType` of the window.
+        vivante_display: pointer(sys::video::SDL_PROP_WINDOW_VIVANTE_DISPLAY_POINTER),
+        /// The `EGLNativeWindowType` of the window.
+        vivante_window: pointer(sys::video::SDL_PROP_WINDOW_VIVANTE_WINDOW_POINTER),
+        /// The `EGLSurface` of the window.
+        vivante_surface: pointer(sys::video::SDL_PROP_WINDOW_VIVANTE_SURFACE_POINTER),
+        /// The `HWND` of the window.
+        win32_hwnd: pointer(sys::video::SDL_PROP_WINDOW_WIN32_HWND_POINTER),
+        /// The `HDC` of the window.
+        win32_hdc: pointer(sys::video::SDL_PROP_WINDOW_WIN32_HDC_POINTER),
+        /// The `HINSTANCE` of the window.
+        win32_instance: pointer(sys::video::SDL_PROP_WINDOW_WIN32_INSTANCE_POINTER),
+        /// The `wl_display` of the window.
+        wayland_display: pointer(sys::video::SDL_PROP_WINDOW_WAYLAND_DISPLAY_POINTER),
+        /// The `wl_surface` of the window.
+        wayland_surface: pointer(sys::video::SDL_PROP_WINDOW_WAYLAND_SURFACE_POINTER),
+        /// The `wp_viewport` of the window.
+        wayland_viewport: pointer(sys::video::SDL_PROP_WINDOW_WAYLAND_VIEWPORT_POINTER),
+        /// The `wl_egl_window` of the window.
+        wayland_egl_window: pointer(sys::video::SDL_PROP_WINDOW_WAYLAND_EGL_WINDOW_POINTER),
+        /// The `xdg_surface` of the window.
+        wayland_xdg_surface: pointer(sys::video::SDL_PROP_WINDOW_WAYLAND_XDG_SURFACE_POINTER),
+        /// The `xdg_toplevel` role of the window.
+        wayland_xdg_toplevel: pointer(sys::video::SDL_PROP_WINDOW_WAYLAND_XDG_TOPLEVEL_POINTER),
+        /// The export handle of the `xdg_toplevel`, for use with `xdg-foreign`.
+        wayland_xdg_toplevel_export_handle: string(sys::video::SDL_PROP_WINDOW_WAYLAND_XDG_TOPLEVEL_EXPORT_HANDLE_STRING),
+        /// The `xdg_popup` role of the window.
+        wayland_xdg_popup: pointer(sys::video::SDL_PROP_WINDOW_WAYLAND_XDG_POPUP_POINTER),
+        /// The `xdg_positioner` of the popup window.
+        wayland_xdg_positioner: pointer(sys::video::SDL_PROP_WINDOW_WAYLAND_XDG_POSITIONER_POINTER),
+        /// The X11 `Display` of the window.
+        x11_display: pointer(sys::video::SDL_PROP_WINDOW_X11_DISPLAY_POINTER),
+        /// The X11 screen of the window.
+        x11_screen: number(sys::video::SDL_PROP_WINDOW_X11_SCREEN_NUMBER),
+        /// The X11 `Window` of the window.
+        x11_window: number(sys::video::SDL_PROP_WINDOW_X11_WINDOW_NUMBER),
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Display {
     pub(crate) id: Uint32,
 }
@@ -722,13 +815,8 @@ impl Display {
     }
 
     #[doc(alias = "SDL_GetDisplayProperties")]
-    pub fn get_properties(&self) -> Result<Properties, PropertiesError> {
-        let internal = unsafe { sys::video::SDL_GetDisplayProperties(self.id) };
-        if internal == 0 {
-            Err(PropertiesError::SdlError(get_error()))
-        } else {
-            Ok(Properties::const_from_ll(internal))
-        }
+    pub fn get_properties(&self) -> Result<DisplayProperties, PropertiesError> {
+        DisplayProperties::from_ll(unsafe { sys::video::SDL_GetDisplayProperties(self.id) })
     }
 
     /// Get the name of the display at the index `display_name`.
@@ -1615,6 +1703,12 @@ impl Window {
         unsafe { sys::video::SDL_GetWindowID(self.context.raw) }
     }
 
+    /// Returns the properties of the window, e.g. its native handles.
+    #[doc(alias = "SDL_GetWindowProperties")]
+    pub fn get_properties(&self) -> Result<WindowProperties, PropertiesError> {
+        WindowProperties::from_ll(unsafe { sys::video::SDL_GetWindowProperties(self.context.raw) })
+    }
+
     #[doc(alias = "SDL_GL_CreateContext")]
     pub fn gl_create_context(&self) -> Result<GLContext, Error> {
         let result = unsafe { sys::video::SDL_GL_CreateContext(self.context.raw) };
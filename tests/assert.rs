@@ -0,0 +1,39 @@
+extern crate sdl3;
+
+use sdl3::assert::{self, AssertState};
+use std::sync::{Arc, Mutex};
+
+// Parallel tests would see each other's failures in the report.
+#[test]
+fn assertion_handler() {
+    let failures = Arc::new(Mutex::new(Vec::new()));
+    {
+        let failures = failures.clone();
+        assert::set_handler(move |assertion| {
+            failures.lock().unwrap().push(assertion.clone());
+            AssertState::Ignore
+        });
+    }
+
+    for _ in 0..2 {
+        sdl3::sys::SDL_assert_always!(1 + 1 == 3);
+    }
+
+    let failures = failures.lock().unwrap();
+    assert_eq!(failures.len(), 2);
+    assert_eq!(failures[0].condition, "1 + 1 == 3");
+    assert_eq!(failures[1].trigger_count, 2);
+
+    let report = assert::report();
+    assert_eq!(report.len(), 1);
+    assert_eq!(report[0].condition, "1 + 1 == 3");
+    assert_eq!(report[0].trigger_count, 2);
+
+    assert::reset_report();
+    assert!(assert::report().is_empty());
+
+    assert::set_handler(assert::log_handler);
+    sdl3::sys::SDL_assert_always!(false);
+    assert_eq!(assert::report().len(), 1);
+    assert::reset_handler();
+}
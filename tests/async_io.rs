@@ -0,0 +1,39 @@
+extern crate sdl3;
+
+use sdl3::async_io::{load_file_async, AsyncIO, AsyncIOQueue, AsyncIOResult, AsyncIOTaskType};
+
+#[test]
+fn write_read_and_load() {
+    let path = std::env::temp_dir().join(format!("sdl3-async-io-test-{}", std::process::id()));
+    let queue = AsyncIOQueue::new().unwrap();
+
+    let file = AsyncIO::from_file(&path, "w").unwrap();
+    let write = file.write(b"hello async".to_vec(), 0, &queue).unwrap();
+    let outcome = queue.wait_result().unwrap();
+    assert_eq!(outcome.task, write);
+    assert_eq!(outcome.task_type, AsyncIOTaskType::Write);
+    assert_eq!(outcome.result, AsyncIOResult::Complete);
+    assert_eq!(outcome.bytes_transferred, 11);
+    assert_eq!(outcome.buffer, b"hello async");
+
+    let close = file.close(true, &queue).unwrap();
+    let outcome = pollster::block_on(queue.next_outcome());
+    assert_eq!(outcome.task, close);
+    assert_eq!(outcome.result, AsyncIOResult::Complete);
+
+    let file = AsyncIO::from_file(&path, "r").unwrap();
+    assert_eq!(file.size().unwrap(), 11);
+    let read = file.read(vec![0; 32], 6, &queue).unwrap();
+    let outcome = queue.wait_result().unwrap();
+    assert_eq!(outcome.task, read);
+    assert_eq!(outcome.buffer, b"async");
+    drop(file);
+
+    let load = load_file_async(&path, &queue).unwrap();
+    let outcome = queue.wait_result().unwrap();
+    assert_eq!(outcome.task, load);
+    assert_eq!(outcome.buffer, b"hello async");
+    assert_eq!(queue.pending(), 0);
+
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,18 @@
+extern crate sdl3;
+
+use sdl3::hint;
+
+#[test]
+fn dummy_camera_driver() {
+    hint::set(hint::names::CAMERA_DRIVER, "dummy");
+
+    let sdl_context = sdl3::init().unwrap();
+    let camera_subsystem = sdl_context.camera().unwrap();
+
+    assert!(sdl3::camera::drivers().any(|driver| driver == "dummy"));
+    assert_eq!(camera_subsystem.current_driver(), Some("dummy"));
+
+    // The dummy driver never reports any devices.
+    assert!(camera_subsystem.cameras().unwrap().is_empty());
+    assert!(camera_subsystem.open(0, None).is_err());
+}
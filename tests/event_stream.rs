@@ -0,0 +1,44 @@
+#![cfg(feature = "async")]
+
+extern crate sdl3;
+
+use futures_core::Stream;
+use sdl3::event::Event;
+use std::future::poll_fn;
+use std::pin::Pin;
+
+#[test]
+fn stream_yields_pushed_events() {
+    let sdl = sdl3::init().unwrap();
+    let ev = sdl.event().unwrap();
+    let mut event_pump = sdl.event_pump().unwrap();
+    let user_event_id = unsafe { ev.register_event().unwrap() };
+
+    let sender = ev.event_sender();
+    let thread = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        sender
+            .push_event(Event::User {
+                timestamp: 0,
+                window_id: 0,
+                type_: user_event_id,
+                code: 7,
+                data1: std::ptr::null_mut(),
+                data2: std::ptr::null_mut(),
+            })
+            .unwrap();
+    });
+
+    let mut events = event_pump.stream();
+    let code = pollster::block_on(async {
+        loop {
+            match poll_fn(|cx| Pin::new(&mut events).poll_next(cx)).await {
+                Some(Event::User { code, .. }) => break code,
+                Some(_) => {}
+                None => panic!("event stream ended"),
+            }
+        }
+    });
+    assert_eq!(code, 7);
+    thread.join().unwrap();
+}
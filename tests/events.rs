@@ -145,3 +145,159 @@ fn test_event_sender_no_subsystem() {
         })
         .is_err());
 }
+
+fn user_event(type_: u32, code: i32) -> event::Event {
+    event::Event::User {
+        timestamp: 0,
+        window_id: 0,
+        type_,
+        code,
+        data1: std::ptr::null_mut(),
+        data2: std::ptr::null_mut(),
+    }
+}
+
+fn user_event_code(event: Option<event::Event>) -> Option<i32> {
+    match event {
+        Some(event::Event::User { code, .. }) => Some(code),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_event_filter() {
+    let _lock = CONTEXT_MUTEX.lock();
+    let sdl = sdl3::init().unwrap();
+    let ev = sdl.event().unwrap();
+    let mut ep = sdl.event_pump().unwrap();
+    let user_event_id = unsafe { ev.register_event().unwrap() };
+
+    assert!(ev
+        .set_event_filter(|event| !matches!(event, event::Event::User { code: 1, .. }))
+        .is_none());
+    assert!(ev.has_event_filter());
+
+    // A vetoed event is reported as not pushed.
+    assert!(ev.push_event(user_event(user_event_id, 1)).is_err());
+    ev.push_event(user_event(user_event_id, 2)).unwrap();
+    assert_eq!(user_event_code(ep.poll_event()), Some(2));
+    assert!(ep.poll_event().is_none());
+
+    // Events pushed by the filter itself bypass it.
+    let sender = ev.event_sender();
+    ev.set_event_filter(move |event| match *event {
+        event::Event::User { type_, code: 5, .. } => {
+            sender.push_event(user_event(type_, 5)).unwrap();
+            false
+        }
+        _ => true,
+    });
+    assert!(ev.push_event(user_event(user_event_id, 5)).is_err());
+    assert_eq!(user_event_code(ep.poll_event()), Some(5));
+    assert!(ep.poll_event().is_none());
+
+    assert!(ev.clear_event_filter().is_some());
+    assert!(!ev.has_event_filter());
+
+    ev.push_event(user_event(user_event_id, 3)).unwrap();
+    ev.push_event(user_event(user_event_id, 4)).unwrap();
+    ev.filter_events(|event| !matches!(event, event::Event::User { code: 3, .. }));
+    assert_eq!(user_event_code(ep.poll_event()), Some(4));
+}
+
+#[test]
+fn test_device_events() {
+    let _lock = CONTEXT_MUTEX.lock();
+    let sdl = sdl3::init().unwrap();
+    let ev = sdl.event().unwrap();
+    let mut ep = sdl.event_pump().unwrap();
+
+    // Every listed device has a name.
+    let keyboard = sdl.keyboard();
+    for id in keyboard.keyboards().unwrap() {
+        keyboard.name_for_id(id).unwrap();
+    }
+    let mouse = sdl.mouse();
+    for id in mouse.mice().unwrap() {
+        mouse.name_for_id(id).unwrap();
+    }
+
+    ev.push_event(event::Event::KeyboardAdded {
+        timestamp: 0,
+        which: 7,
+    })
+    .unwrap();
+    ev.push_event(event::Event::MouseRemoved {
+        timestamp: 0,
+        which: 8,
+    })
+    .unwrap();
+
+    let events: Vec<_> = ep.poll_iter().collect();
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, event::Event::KeyboardAdded { which: 7, .. }) && e.is_keyboard()));
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, event::Event::MouseRemoved { which: 8, .. }) && e.is_mouse()));
+}
+
+fn text_inputs(ep: &mut sdl3::EventPump) -> Vec<String> {
+    ep.poll_iter()
+        .filter_map(|event| match event {
+            event::Event::TextInput { text, .. } => Some(text),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_event_strings() {
+    let _lock = CONTEXT_MUTEX.lock();
+    let sdl = sdl3::init().unwrap();
+    let ev = sdl.event().unwrap();
+    let mut ep = sdl.event_pump().unwrap();
+
+    let text_input = |text: &str| event::Event::TextInput {
+        timestamp: 0,
+        window_id: 0,
+        text: text.to_owned(),
+    };
+    ev.push_event(text_input("flushed")).unwrap();
+    ev.flush_event(event::EventType::TextInput);
+    ev.push_event(text_input("first")).unwrap();
+    ev.push_event(text_input("second")).unwrap();
+    assert_eq!(text_inputs(&mut ep), ["first", "second"]);
+
+    // The strings of converted events stay valid until the next poll.
+    let raw = text_input("converted").to_ll().unwrap();
+    assert_eq!(event::Event::from_ll(raw), text_input("converted"));
+}
+
+#[test]
+fn test_event_string_lists() {
+    let _lock = CONTEXT_MUTEX.lock();
+    let sdl = sdl3::init().unwrap();
+    let ev = sdl.event().unwrap();
+    let mut ep = sdl.event_pump().unwrap();
+
+    let candidates = event::Event::TextEditingCandidates {
+        timestamp: 0,
+        window_id: 0,
+        candidates: vec!["日本".to_owned(), "二本".to_owned()],
+        selected_candidate: Some(1),
+        horizontal: true,
+    };
+    ev.push_event(candidates.clone()).unwrap();
+    ev.flush_event(event::EventType::TextEditingCandidates);
+    ev.push_event(candidates).unwrap();
+
+    let polled: Vec<_> = ep
+        .poll_iter()
+        .filter_map(|event| match event {
+            event::Event::TextEditingCandidates { candidates, .. } => Some(candidates),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(polled, [["日本", "二本"]]);
+}
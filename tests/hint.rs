@@ -0,0 +1,62 @@
+extern crate sdl3;
+
+use sdl3::hint::{self, HintPriority, RenderLineMethod};
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn typed_hints() {
+    assert!(hint::RENDER_VSYNC.set(true));
+    assert_eq!(hint::get(hint::names::RENDER_VSYNC).as_deref(), Some("1"));
+    assert_eq!(hint::RENDER_VSYNC.get(), Some(true));
+
+    hint::set(hint::names::RENDER_VSYNC, "false");
+    assert_eq!(hint::RENDER_VSYNC.get(), Some(false));
+
+    assert!(hint::RENDER_LINE_METHOD.set(RenderLineMethod::Geometry));
+    assert_eq!(
+        hint::get(hint::names::RENDER_LINE_METHOD).as_deref(),
+        Some("3")
+    );
+    assert_eq!(
+        hint::RENDER_LINE_METHOD.get(),
+        Some(RenderLineMethod::Geometry)
+    );
+
+    assert!(hint::AUDIO_FREQUENCY.set(48000));
+    assert_eq!(hint::AUDIO_FREQUENCY.get(), Some(48000));
+
+    assert!(hint::AUDIO_DEVICE_STREAM_NAME.set("Music"));
+    assert_eq!(
+        hint::AUDIO_DEVICE_STREAM_NAME.get().as_deref(),
+        Some("Music")
+    );
+
+    assert!(hint::AUDIO_FREQUENCY.reset());
+    assert_eq!(hint::AUDIO_FREQUENCY.get(), None);
+}
+
+#[test]
+fn hint_priority() {
+    assert!(hint::MOUSE_DOUBLE_CLICK_TIME.set_with_priority(500, HintPriority::Override));
+    assert!(!hint::MOUSE_DOUBLE_CLICK_TIME.set(200));
+    assert_eq!(hint::MOUSE_DOUBLE_CLICK_TIME.get(), Some(500));
+}
+
+#[test]
+fn hint_callbacks() {
+    let values = Arc::new(Mutex::new(Vec::new()));
+
+    hint::JOYSTICK_THREAD.set(false);
+    let guard = {
+        let values = values.clone();
+        hint::JOYSTICK_THREAD
+            .add_callback(move |value| values.lock().unwrap().push(value))
+            .unwrap()
+    };
+    hint::JOYSTICK_THREAD.set(true);
+    drop(guard);
+    hint::JOYSTICK_THREAD.set(false);
+
+    // The callback is called once with the current value when added.
+    assert_eq!(*values.lock().unwrap(), [Some(false), Some(true)]);
+}
@@ -0,0 +1,65 @@
+extern crate sdl3;
+
+use sdl3::iostream::{IOStatus, IOStream};
+use std::io::{self, Cursor, Read, Seek, SeekFrom, Write};
+
+#[test]
+fn interface_stream() {
+    let mut data = b"hello world".to_vec();
+    {
+        let mut stream = IOStream::from_interface(Cursor::new(&mut data)).unwrap();
+        assert_eq!(stream.len(), Some(11));
+
+        let mut buf = [0; 5];
+        stream.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"hello");
+
+        stream.seek(SeekFrom::Start(6)).unwrap();
+        stream.write_all(b"there").unwrap();
+        stream.seek(SeekFrom::End(0)).unwrap();
+        assert_eq!(stream.read(&mut buf).unwrap(), 0);
+        assert_eq!(stream.status(), IOStatus::EOF);
+    }
+    assert_eq!(data, b"hello there");
+}
+
+struct Failing;
+
+impl Read for Failing {
+    fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+        Err(io::Error::other("read failed"))
+    }
+}
+
+impl Write for Failing {
+    fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+        panic!("write panicked")
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Err(io::Error::other("flush failed"))
+    }
+}
+
+impl Seek for Failing {
+    fn seek(&mut self, _pos: SeekFrom) -> io::Result<u64> {
+        Err(io::Error::other("seek failed"))
+    }
+}
+
+#[test]
+fn interface_errors() {
+    let mut stream = IOStream::from_interface(Failing).unwrap();
+    assert_eq!(stream.len(), None);
+
+    let error = stream.read(&mut [0; 4]).unwrap_err();
+    assert!(error.to_string().contains("read failed"));
+    assert_eq!(stream.status(), IOStatus::Error);
+    assert!(stream.seek(SeekFrom::Start(0)).is_err());
+
+    let error = stream.write(b"data").unwrap_err();
+    assert!(error.to_string().contains("panicked"));
+    assert!(stream.flush().is_err());
+    // Closing succeeds even though the final flush fails.
+    drop(stream);
+}
@@ -0,0 +1,11 @@
+extern crate sdl3;
+
+use sdl3::locale::preferred_locales;
+
+#[test]
+fn preferred_locales_are_well_formed() {
+    for locale in preferred_locales() {
+        assert!(!locale.language.is_empty());
+        assert_ne!(locale.country.as_deref(), Some(""));
+    }
+}
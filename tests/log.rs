@@ -0,0 +1,56 @@
+extern crate sdl3;
+
+use sdl3::log::{self, Category, Priority};
+use std::sync::{Arc, Mutex};
+
+// The output function is global, so everything is checked in one test.
+#[test]
+fn output_closure() {
+    let messages = Arc::new(Mutex::new(Vec::new()));
+
+    let previous = {
+        let messages = messages.clone();
+        log::set_output_closure(move |priority, category, message| {
+            messages
+                .lock()
+                .unwrap()
+                .push((priority, category, message.to_owned()));
+        })
+    };
+
+    log::set_log_priority(Category::Video, Priority::Debug);
+    log::log_message(Category::Video, Priority::Debug, "100% captured");
+    log::log_message(Category::Video, Priority::Verbose, "filtered by SDL");
+    assert_eq!(
+        *messages.lock().unwrap(),
+        [(Priority::Debug, Category::Video, "100% captured".to_owned())]
+    );
+
+    #[cfg(feature = "log")]
+    {
+        sdl3::log::Logger::init(::log::LevelFilter::Trace).unwrap();
+        log::set_log_priority(Category::Render, Priority::Warn);
+        ::log::warn!(target: "sdl3::render", "through the log crate");
+        ::log::info!(target: "other", "as an application message");
+        assert_eq!(
+            messages.lock().unwrap()[1..],
+            [
+                (
+                    Priority::Warn,
+                    Category::Render,
+                    "through the log crate".to_owned()
+                ),
+                (
+                    Priority::Info,
+                    Category::Application,
+                    "as an application message".to_owned()
+                ),
+            ]
+        );
+    }
+
+    previous.restore();
+    let count = messages.lock().unwrap().len();
+    log::log_message(Category::Video, Priority::Error, "not captured");
+    assert_eq!(messages.lock().unwrap().len(), count);
+}
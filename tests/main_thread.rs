@@ -0,0 +1,35 @@
+extern crate sdl3;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+#[test]
+fn run_on_main_thread() {
+    let sdl_context = sdl3::init().unwrap();
+    let _event_subsystem = sdl_context.event().unwrap();
+    let mut event_pump = sdl_context.event_pump().unwrap();
+
+    assert!(sdl3::is_main_thread());
+
+    let ran = Arc::new(AtomicBool::new(false));
+    let ran_in_worker = ran.clone();
+    thread::spawn(move || {
+        assert!(!sdl3::is_main_thread());
+        sdl3::run_on_main_thread(
+            move || {
+                assert!(sdl3::is_main_thread());
+                ran_in_worker.store(true, Ordering::SeqCst);
+            },
+            false,
+        )
+        .unwrap();
+    })
+    .join()
+    .unwrap();
+
+    // Queued callbacks run while the main thread processes events.
+    assert!(!ran.load(Ordering::SeqCst));
+    event_pump.pump_events();
+    assert!(ran.load(Ordering::SeqCst));
+}
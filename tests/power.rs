@@ -0,0 +1,12 @@
+extern crate sdl3;
+
+use sdl3::power::{power_info, PowerLevel};
+
+#[test]
+fn host_power_info() {
+    let info = power_info().unwrap();
+    assert_ne!(info.state, PowerLevel::Error);
+    if let Some(percent) = info.percent_left {
+        assert!(percent <= 100);
+    }
+}
@@ -0,0 +1,50 @@
+#![cfg(unix)]
+extern crate sdl3;
+
+use sdl3::process::{ProcessBuilder, Stdio};
+use std::io::{Read, Write};
+
+#[test]
+fn read_output_and_exit_code() {
+    let mut process = ProcessBuilder::new("sh")
+        .args(["-c", "echo $GREETING; exit 3"])
+        .env("GREETING", "hello")
+        .stdout(Stdio::App)
+        .spawn()
+        .unwrap();
+
+    let (output, exit_code) = process.read_output().unwrap();
+    assert_eq!(output, b"hello\n");
+    assert_eq!(exit_code, 3);
+    assert_eq!(process.wait(true), Some(3));
+}
+
+#[test]
+fn piped_input() {
+    let mut process = ProcessBuilder::new("cat")
+        .stdin(Stdio::App)
+        .stdout(Stdio::App)
+        .spawn()
+        .unwrap();
+    assert!(process.pid() > 0);
+
+    process.input().unwrap().write_all(b"ping").unwrap();
+    process.close_input();
+
+    let mut output = Vec::new();
+    let mut buf = [0; 16];
+    while output.len() < 4 {
+        let n = process.output().unwrap().read(&mut buf).unwrap();
+        output.extend_from_slice(&buf[..n]);
+    }
+    assert_eq!(output, b"ping");
+    assert_eq!(process.wait(true), Some(0));
+}
+
+#[test]
+fn kill() {
+    let mut process = ProcessBuilder::new("sleep").arg("60").spawn().unwrap();
+    assert_eq!(process.wait(false), None);
+    process.kill(true).unwrap();
+    assert!(process.wait(true).unwrap() < 0);
+}
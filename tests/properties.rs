@@ -0,0 +1,73 @@
+extern crate sdl3;
+
+use sdl3::properties::{Getter, Properties, PropertiesError, PropertyType, PropertyValue, Setter};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+#[test]
+fn typed_properties() {
+    let properties = Properties::new().unwrap();
+    properties.set("number", 42i64).unwrap();
+    properties.set("string", String::from("hello")).unwrap();
+    properties.set("value", PropertyValue::Float(0.5)).unwrap();
+
+    let string: String = properties.get("string", String::new()).unwrap();
+    assert_eq!(string, "hello");
+    let missing: String = properties.get("missing", String::from("default")).unwrap();
+    assert_eq!(missing, "default");
+
+    assert_eq!(
+        properties.get_value("number").unwrap(),
+        Some(PropertyValue::Number(42))
+    );
+    assert_eq!(
+        properties.get_value("value").unwrap(),
+        Some(PropertyValue::Float(0.5))
+    );
+    assert_eq!(properties.get_value("missing").unwrap(), None);
+
+    let mut entries: Vec<_> = properties.iter().unwrap().collect();
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    assert!(entries
+        .iter()
+        .map(|(name, _)| name)
+        .eq(["number", "string", "value"].iter()));
+    assert!(entries[0].1 == PropertyType::NUMBER);
+    assert!(entries[1].1 == PropertyType::STRING);
+    assert!(entries[2].1 == PropertyType::FLOAT);
+
+    let locked = properties.lock().unwrap();
+    assert!(locked.contains("number").unwrap());
+}
+
+#[test]
+fn pointer_cleanup() {
+    let cleaned = Arc::new(AtomicBool::new(false));
+    let mut properties = Properties::new().unwrap();
+
+    let value = Box::into_raw(Box::new(7u32));
+    let flag = cleaned.clone();
+    properties
+        .set_with_cleanup("pointer", value, move |value| {
+            assert_eq!(unsafe { *Box::from_raw(value) }, 7);
+            flag.store(true, Ordering::SeqCst);
+        })
+        .unwrap();
+    let value = unsafe { properties.with("pointer", |value: &u32| *value) };
+    assert_eq!(value.unwrap(), 7);
+
+    properties.clear("pointer").unwrap();
+    assert!(cleaned.load(Ordering::SeqCst));
+}
+
+#[test]
+fn with_missing_pointer() {
+    let properties = Properties::new().unwrap();
+    properties.set("number", 1i64).unwrap();
+    for name in ["missing", "number"] {
+        assert!(matches!(
+            unsafe { properties.with(name, |value: &u32| *value) },
+            Err(PropertiesError::NullPointer)
+        ));
+    }
+}
@@ -1,5 +1,7 @@
 extern crate sdl3;
-use sdl3::{rect::Rect, render::create_renderer, render::ClippingRect};
+use sdl3::pixels::PixelFormat;
+use sdl3::sys::pixels::SDL_PixelFormat;
+use sdl3::{rect::Rect, render::create_renderer, render::ClippingRect, render::TextureAccess};
 
 #[test]
 fn clipping_rect_intersection() {
@@ -96,3 +98,33 @@ fn creating_a_named_renderer() {
     // the software renderer should always be available
     create_renderer(window, Some(c"software")).unwrap();
 }
+
+#[test]
+fn renderer_and_texture_properties() {
+    let window = sdl3::init()
+        .unwrap()
+        .video()
+        .unwrap()
+        .window("Hello, World!", 800, 600)
+        .hidden()
+        .build()
+        .unwrap();
+    assert!(window.get_properties().is_ok());
+
+    let canvas = create_renderer(window, Some(c"software")).unwrap();
+    let properties = canvas.get_properties().unwrap();
+    assert_eq!(properties.name().as_deref(), Some("software"));
+    assert!(properties.max_texture_size().is_some());
+    assert!(!properties.texture_formats().is_empty());
+
+    let format = unsafe { PixelFormat::from_ll(SDL_PixelFormat::RGBA8888) };
+    let texture_creator = canvas.texture_creator();
+    let texture = texture_creator
+        .create_texture_streaming(format, 16, 8)
+        .unwrap();
+    let properties = texture.get_properties().unwrap();
+    assert_eq!(properties.width(), Some(16));
+    assert_eq!(properties.height(), Some(8));
+    assert_eq!(properties.format(), Some(format));
+    assert_eq!(properties.access(), Some(TextureAccess::Streaming));
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "serde")]
+
+extern crate sdl3;
+
+use sdl3::event::Event;
+use sdl3::keyboard::{Keycode, Mod, Scancode};
+use sdl3::replay::{read_events, EventPlayer, EventRecorder, ReplayError};
+
+fn sample_events() -> Vec<Event> {
+    vec![
+        Event::KeyDown {
+            timestamp: 1_000,
+            window_id: 1,
+            keycode: Some(Keycode::A),
+            scancode: Some(Scancode::A),
+            keymod: Mod::LSHIFTMOD,
+            repeat: false,
+            which: 0,
+            raw: 4,
+        },
+        Event::TextInput {
+            timestamp: 2_000,
+            window_id: 1,
+            text: "A".to_owned(),
+        },
+        Event::DropFile {
+            timestamp: 5_000,
+            window_id: 1,
+            filename: "/tmp/dropped.txt".to_owned(),
+        },
+        Event::Quit { timestamp: 9_000 },
+    ]
+}
+
+fn record(events: &[Event]) -> Vec<u8> {
+    let mut recorder = EventRecorder::new(Vec::new()).unwrap();
+    for event in events {
+        recorder.record(event).unwrap();
+    }
+    recorder.into_inner().unwrap()
+}
+
+#[test]
+fn recording_round_trip() {
+    let events = sample_events();
+    let recorded = read_events(&record(&events)[..]).unwrap();
+
+    let times: Vec<u64> = recorded.iter().map(|r| r.time_ns).collect();
+    assert_eq!(times, [0, 1_000, 4_000, 8_000]);
+    let replayed: Vec<Event> = recorded.into_iter().map(|r| r.event).collect();
+    assert_eq!(replayed, events);
+}
+
+#[test]
+fn rejects_invalid_data() {
+    assert!(matches!(
+        read_events(&b"not a recording"[..]),
+        Err(ReplayError::InvalidFormat)
+    ));
+
+    let mut recorder = EventRecorder::new(Vec::new()).unwrap();
+    let user_event = Event::User {
+        timestamp: 0,
+        window_id: 0,
+        type_: 0x8000,
+        code: 0,
+        data1: 0x1234 as *mut libc::c_void,
+        data2: std::ptr::null_mut(),
+    };
+    assert!(matches!(
+        recorder.record(&user_event),
+        Err(ReplayError::UnsupportedEvent(_))
+    ));
+}
+
+#[test]
+fn stepped_playback() {
+    let sdl = sdl3::init().unwrap();
+    let ev = sdl.event().unwrap();
+    let mut event_pump = sdl.event_pump().unwrap();
+    for _ in event_pump.poll_iter() {}
+
+    let data = record(&sample_events());
+    let mut player = EventPlayer::new(&ev, &data[..]).unwrap();
+
+    assert_eq!(player.advance_to(1_500).unwrap(), 2);
+    let polled: Vec<Event> = event_pump.poll_iter().collect();
+    assert!(matches!(
+        polled[..],
+        [Event::KeyDown { .. }, Event::TextInput { .. }]
+    ));
+
+    assert!(player.step().unwrap().is_some());
+    assert!(matches!(
+        event_pump.poll_event(),
+        Some(Event::DropFile { ref filename, .. }) if filename == "/tmp/dropped.txt"
+    ));
+
+    assert_eq!(player.advance_to(u64::MAX).unwrap(), 1);
+    assert!(player.is_finished());
+    assert!(player.step().unwrap().is_none());
+
+    // Events dropped by the filter are skipped instead of retried.
+    ev.set_event_filter(|event| !matches!(event, Event::TextInput { .. }));
+    player.rewind();
+    assert!(player.step().is_ok());
+    assert!(player.step().is_err());
+    assert_eq!(player.position(), 2);
+    assert_eq!(player.advance_to(u64::MAX).unwrap(), 2);
+    assert!(player.is_finished());
+    ev.clear_event_filter();
+
+    let polled: Vec<Event> = event_pump.poll_iter().collect();
+    assert_eq!(polled.len(), 3);
+    assert!(!polled
+        .iter()
+        .any(|event| matches!(event, Event::TextInput { .. })));
+}
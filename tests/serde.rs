@@ -0,0 +1,108 @@
+#![cfg(feature = "serde")]
+
+extern crate sdl3;
+
+use sdl3::event::{Event, WindowEvent};
+use sdl3::gamepad::{Axis, Button};
+use sdl3::keyboard::{Keycode, Mod, Scancode};
+use sdl3::mouse::MouseButton;
+use sdl3::rect::{Point, Rect};
+
+fn round_trip<T>(value: &T) -> T
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    let json = serde_json::to_string(value).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+#[test]
+fn keys_use_sdl_names() {
+    assert_eq!(serde_json::to_string(&Keycode::A).unwrap(), "\"A\"");
+    assert_eq!(
+        serde_json::to_string(&Scancode::LShift).unwrap(),
+        "\"Left Shift\""
+    );
+    assert_eq!(
+        serde_json::from_str::<Keycode>("\"Return\"").unwrap(),
+        Keycode::Return
+    );
+    assert_eq!(
+        serde_json::from_str::<Scancode>("\"\"").unwrap(),
+        Scancode::Unknown
+    );
+    assert!(serde_json::from_str::<Keycode>("\"No Such Key\"").is_err());
+
+    for keycode in [Keycode::Unknown, Keycode::Space, Keycode::F12, Keycode::Kp0] {
+        assert_eq!(round_trip(&keycode), keycode);
+    }
+    for scancode in [
+        Scancode::Unknown,
+        Scancode::Z,
+        Scancode::Escape,
+        Scancode::Kp0,
+    ] {
+        assert_eq!(round_trip(&scancode), scancode);
+    }
+}
+
+#[test]
+fn every_key_round_trips() {
+    // `Return2` is also named "Return", so it has to be serialized by value.
+    assert_eq!(
+        serde_json::to_string(&Scancode::Return2).unwrap(),
+        (Scancode::Return2 as i32).to_string()
+    );
+
+    for scancode in (0..512).filter_map(Scancode::from_i32) {
+        assert_eq!(round_trip(&scancode), scancode);
+    }
+    let keycodes = (0..0x100).chain((1 << 30)..(1 << 30) + 512);
+    for keycode in keycodes.filter_map(Keycode::from_i32) {
+        assert_eq!(round_trip(&keycode), keycode);
+    }
+}
+
+#[test]
+fn input_types() {
+    let keymod = Mod::LSHIFTMOD | Mod::RCTRLMOD;
+    assert_eq!(round_trip(&keymod), keymod);
+    assert_eq!(round_trip(&MouseButton::X1), MouseButton::X1);
+    assert_eq!(round_trip(&Button::DPadLeft), Button::DPadLeft);
+    assert_eq!(round_trip(&Axis::TriggerRight), Axis::TriggerRight);
+
+    let rect = Rect::new(-4, 5, 20, 30);
+    assert_eq!(round_trip(&rect), rect);
+    let point = Point::new(7, -8);
+    assert_eq!(round_trip(&point), point);
+    assert_eq!(serde_json::to_string(&point).unwrap(), r#"{"x":7,"y":-8}"#);
+}
+
+#[test]
+fn events() {
+    let events = [
+        Event::KeyDown {
+            timestamp: 1_000,
+            window_id: 1,
+            keycode: Some(Keycode::Left),
+            scancode: Some(Scancode::Left),
+            keymod: Mod::LALTMOD,
+            repeat: false,
+            which: 0,
+            raw: 0,
+        },
+        Event::Window {
+            timestamp: 2_000,
+            window_id: 1,
+            win_event: WindowEvent::Resized(640, 480),
+        },
+        Event::TextInput {
+            timestamp: 3_000,
+            window_id: 1,
+            text: "héllo".to_owned(),
+        },
+    ];
+    for event in &events {
+        assert_eq!(&round_trip(event), event);
+    }
+}
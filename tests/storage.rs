@@ -0,0 +1,57 @@
+extern crate sdl3;
+
+use sdl3::filesystem::{EnumerationResult, GlobFlags};
+use sdl3::storage::open_file_storage;
+use std::path::PathBuf;
+
+#[test]
+fn file_storage_round_trip() {
+    let dir = std::env::temp_dir().join(format!("sdl3-storage-test-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    // SDL concatenates the base and relative paths without adding a separator.
+    let mut base = dir.to_str().unwrap().to_owned();
+    base.push('/');
+
+    let storage = open_file_storage(&base).unwrap();
+    assert!(storage.is_ready());
+
+    storage.create_directory("saves").unwrap();
+    storage.write_file("saves/slot1.dat", b"level 3").unwrap();
+    assert_eq!(storage.file_size("saves/slot1.dat").unwrap(), 7);
+    assert_eq!(storage.read_file("saves/slot1.dat").unwrap(), b"level 3");
+
+    assert!(storage.path_info("saves").is_ok());
+
+    storage
+        .copy_file("saves/slot1.dat", "saves/slot2.dat")
+        .unwrap();
+    storage
+        .rename_path("saves/slot2.dat", "saves/slot3.dat")
+        .unwrap();
+
+    let mut entries = Vec::new();
+    storage
+        .enumerate_directory("saves", |_, name| {
+            entries.push(name.to_owned());
+            EnumerationResult::CONTINUE
+        })
+        .unwrap();
+    entries.sort();
+    assert_eq!(
+        entries,
+        [PathBuf::from("slot1.dat"), PathBuf::from("slot3.dat")]
+    );
+
+    let globbed = storage
+        .glob_directory("saves", Some("*3.dat"), GlobFlags::NONE)
+        .unwrap();
+    assert_eq!((&globbed).into_iter().count(), 1);
+
+    storage.remove_path("saves/slot1.dat").unwrap();
+    storage.remove_path("saves/slot3.dat").unwrap();
+    storage.remove_path("saves").unwrap();
+    assert!(storage.path_info("saves").is_err());
+
+    storage.close().unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+}
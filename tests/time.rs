@@ -0,0 +1,27 @@
+extern crate sdl3;
+
+use sdl3::time::{current_time, day_of_week, day_of_year, days_in_month, DateTime};
+
+#[test]
+fn calendar_helpers() {
+    assert_eq!(days_in_month(2024, 2).unwrap(), 29);
+    assert_eq!(days_in_month(2023, 2).unwrap(), 28);
+    assert_eq!(day_of_year(2024, 3, 1).unwrap(), 60);
+    // Jan 1, 1970 was a Thursday.
+    assert_eq!(day_of_week(1970, 1, 1).unwrap(), 4);
+    assert!(days_in_month(2024, 13).is_err());
+}
+
+#[test]
+fn date_time_round_trip() {
+    let epoch = DateTime::from_time(0, false).unwrap();
+    assert_eq!((epoch.year, epoch.month, epoch.day), (1970, 1, 1));
+    assert_eq!(epoch.day_of_week, 4);
+    assert_eq!(epoch.utc_offset, 0);
+
+    let now = current_time().unwrap();
+    for local in [false, true] {
+        let date_time = DateTime::from_time(now, local).unwrap();
+        assert_eq!(date_time.to_time().unwrap(), now);
+    }
+}
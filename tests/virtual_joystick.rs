@@ -0,0 +1,102 @@
+extern crate sdl3;
+#[macro_use]
+extern crate lazy_static;
+
+use sdl3::event::Event;
+use sdl3::gamepad::{Axis, Button};
+use sdl3::joystick::{HatState, VirtualJoystickDesc};
+use std::sync::Mutex;
+
+// Only one `Sdl` context can exist at a time, so the tests run in serial.
+lazy_static! {
+    static ref CONTEXT_MUTEX: Mutex<()> = Mutex::new(());
+}
+
+#[test]
+fn virtual_gamepad() {
+    let _lock = CONTEXT_MUTEX.lock();
+    let sdl_context = sdl3::init().unwrap();
+    let joystick_subsystem = sdl_context.joystick().unwrap();
+    let gamepad_subsystem = sdl_context.gamepad().unwrap();
+    let mut event_pump = sdl_context.event_pump().unwrap();
+
+    let mut virtual_gamepad = VirtualJoystickDesc::new("Virtual Pad")
+        .gamepad()
+        .touchpad(2)
+        .attach(&joystick_subsystem)
+        .unwrap();
+    let id = virtual_gamepad.id();
+
+    assert!(joystick_subsystem.is_virtual(id));
+    assert!(gamepad_subsystem.gamepads().unwrap().contains(&id));
+    let gamepad = gamepad_subsystem.open(id).unwrap();
+    event_pump.pump_events();
+    for _ in event_pump.poll_iter() {}
+
+    virtual_gamepad
+        .set_gamepad_axis(Axis::LeftX, 12000)
+        .unwrap();
+    virtual_gamepad
+        .set_gamepad_button(Button::South, true)
+        .unwrap();
+    virtual_gamepad
+        .set_touchpad(0, 0, true, 0.5, 0.5, 1.0)
+        .unwrap();
+
+    let mut saw_axis = false;
+    let mut saw_button = false;
+    event_pump.pump_events();
+    for event in event_pump.poll_iter() {
+        match event {
+            Event::ControllerAxisMotion {
+                which,
+                axis: Axis::LeftX,
+                value,
+                ..
+            } if which == id => {
+                assert_eq!(value, 12000);
+                saw_axis = true;
+            }
+            Event::ControllerButtonDown {
+                which,
+                button: Button::South,
+                ..
+            } if which == id => saw_button = true,
+            _ => {}
+        }
+    }
+    assert!(saw_axis);
+    assert!(saw_button);
+    assert_eq!(gamepad.axis(Axis::LeftX), 12000);
+
+    drop(gamepad);
+    drop(virtual_gamepad);
+    assert!(!gamepad_subsystem.gamepads().unwrap().contains(&id));
+}
+
+#[test]
+fn virtual_joystick_hats_and_buttons() {
+    let _lock = CONTEXT_MUTEX.lock();
+    let sdl_context = sdl3::init().unwrap();
+    let joystick_subsystem = sdl_context.joystick().unwrap();
+
+    let mut virtual_joystick = VirtualJoystickDesc::new("Virtual Stick")
+        .vendor_id(0x1234)
+        .product_id(0x5678)
+        .axes(2)
+        .buttons(4)
+        .hats(1)
+        .attach(&joystick_subsystem)
+        .unwrap();
+
+    virtual_joystick.set_hat(0, HatState::Up).unwrap();
+    virtual_joystick.set_button(3, true).unwrap();
+    assert!(virtual_joystick.set_button(4, true).is_err());
+    joystick_subsystem.update();
+
+    let joystick = joystick_subsystem.open(virtual_joystick.id()).unwrap();
+    assert_eq!(joystick.num_axes(), 2);
+    assert_eq!(joystick.num_buttons(), 4);
+    assert_eq!(joystick.hat(0).unwrap(), HatState::Up);
+    assert!(joystick.button(3).unwrap());
+}